@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use tuio_rs::client::{Client, CursorEvent, BlobEvent, ObjectEvent, TuioEvents};
 
 fn process_events(events: TuioEvents) {
@@ -31,7 +33,8 @@ fn main() {
     client.connect().expect("Client connecting");
 
     loop {
-        if let Ok(Some(events)) = client.refresh() {
+        // Sleeps until packets are received instead of polling
+        if let Some(events) = client.wait_for_events(Duration::from_secs(1)) {
             process_events(events);
         }
     }
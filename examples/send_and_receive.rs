@@ -1,6 +1,6 @@
 use std::time::{Duration};
 
-use tuio_rs::{Client, client::{TuioEvents, CursorEvent, ObjectEvent, BlobEvent}, Server};
+use tuio_rs::{Client, client::{TuioEvents, CursorEvent, ObjectEvent, BlobEvent}, Server, osc_receiver::ChannelReceiver};
 
 fn process_events(events: TuioEvents) {
     for event in events.cursor_events {
@@ -29,19 +29,21 @@ fn process_events(events: TuioEvents) {
 }
 
 fn main() {
-    let client = Client::new().unwrap();
-    client.connect().expect("Client connecting");
+    // The server feeds the client in-process, without any socket
+    let receiver = ChannelReceiver::new();
+    let mut server = Server::from_osc_sender(receiver.sender());
+    server.set_source_name("server_name");
 
-    let mut server = Server::new("server_name").unwrap();
+    let client = Client::from_osc_receiver(receiver);
+    client.connect().expect("Client connecting");
     
     server.init_frame();
     let cursor_id = server.create_cursor(0., 0.);
     let object_id = server.create_object(1, 0., 0., 0.);
     let blob_id = server.create_blob(0., 0., 0., 0.1, 0.1, 0.01);
-    server.commit_frame();
-    std::thread::sleep(Duration::from_secs(1));
+    server.commit_frame().expect("Sending frame");
 
-    if let Ok(Some(events)) = client.refresh() {
+    if let Some(events) = client.wait_for_events(Duration::from_secs(1)) {
         process_events(events);
     }
 
@@ -49,11 +51,9 @@ fn main() {
     server.update_cursor(cursor_id, 1., 1.);
     server.update_object(object_id, 1., 1., 90f32.to_radians());
     server.update_blob(blob_id, 1., 1., 90f32.to_radians(), 0.2, 0.2, 0.04);
-    server.commit_frame();
+    server.commit_frame().expect("Sending frame");
 
-    std::thread::sleep(Duration::from_secs(1));
-
-    if let Ok(Some(events)) = client.refresh() {
+    if let Some(events) = client.wait_for_events(Duration::from_secs(1)) {
         process_events(events);
     }
 
@@ -61,11 +61,9 @@ fn main() {
     server.remove_cursor(cursor_id);
     server.remove_object(object_id);
     server.remove_blob(blob_id);
-    server.commit_frame();
-
-    std::thread::sleep(Duration::from_secs(1));
+    server.commit_frame().expect("Sending frame");
 
-    if let Ok(Some(events)) = client.refresh() {
+    if let Some(events) = client.wait_for_events(Duration::from_secs(1)) {
         process_events(events);
     }
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+use std::{env, net::{SocketAddr, IpAddr, Ipv4Addr}, process};
+
+use rosc::OscPacket;
+use tuio_rs::{client::Protocol, osc_encode_decode::{OscDecoder, DecodeOsc}, osc_receiver::{OscReceiver, UdpReceiver}, server::{SendOsc, UdpSender}, translator::{Tuio1To2Translator, Tuio2To1Translator}, tuio2::{Tuio2Decoder, DecodeTuio2, Tuio2Encoder, EncodeTuio2}};
+
+/// Forwards the TUIO bundles received on a port to another one, translating TUIO 1.1 into TUIO 2.0 and TUIO 2.0 into TUIO 1.1
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let (listen_port, target_port) = match (args.get(1).map(|port| port.parse::<u16>()), args.get(2).map(|port| port.parse::<u16>())) {
+        (Some(Ok(listen_port)), Some(Ok(target_port))) => (listen_port, target_port),
+        _ => {
+            eprintln!("usage: {} <listen port> <target port>", args[0]);
+            process::exit(1);
+        }
+    };
+
+    let receiver = UdpReceiver::from_port(listen_port).expect("Binding listen port");
+    let sender = UdpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), target_port)).expect("Creating sender");
+
+    let mut tuio1_to_tuio2 = Tuio1To2Translator::new();
+    let mut tuio2_to_tuio1 = Tuio2To1Translator::new();
+
+    loop {
+        let bundle = match receiver.recv() {
+            Ok(OscPacket::Bundle(bundle)) => bundle,
+            Ok(_) => continue,
+            Err(err) => {
+                eprintln!("Error receiving from socket: {}", err);
+                continue;
+            }
+        };
+
+        let translated = match Protocol::detect(&bundle) {
+            Some(Protocol::Tuio1) => OscDecoder::decode_bundle(bundle)
+                .map(|bundle| tuio1_to_tuio2.translate(bundle).map(|frame_bundle| Tuio2Encoder::encode_frame_bundle(&frame_bundle)).into_iter().collect()),
+            Some(Protocol::Tuio2) => Tuio2Decoder::decode_bundle(bundle)
+                .map(|frame_bundle| tuio2_to_tuio1.translate(frame_bundle)),
+            None => continue,
+        };
+
+        match translated {
+            Ok(bundles) => {
+                for bundle in bundles {
+                    if let Err(err) = sender.send_osc_packet(&OscPacket::Bundle(bundle)) {
+                        eprintln!("Error sending OSC packet: {}", err);
+                    }
+                }
+            },
+            Err(err) => eprintln!("Error decoding TUIO bundle: {}", err),
+        }
+    }
+}
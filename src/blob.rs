@@ -1,6 +1,6 @@
 use std::{f32::consts::PI, time::Duration};
 
-use crate::cursor::{Position, Velocity};
+use crate::{cursor::{Position, Velocity}, tuio2::Bounds};
 
 #[derive(Debug, Clone, Default)]
 pub struct Blob {
@@ -201,6 +201,24 @@ impl PartialEq for Blob {
     }
 }
 
+impl From<Bounds> for Blob {
+    /// Converts a TUIO 2 [Bounds] into its TUIO 1.1 equivalent
+    fn from(bounds: Bounds) -> Self {
+        Self {
+            session_id: bounds.session_id,
+            position: bounds.position,
+            velocity: bounds.velocity,
+            acceleration: bounds.acceleration,
+            angle: bounds.angle,
+            rotation_speed: bounds.rotation_speed,
+            rotation_acceleration: bounds.rotation_acceleration,
+            width: bounds.width,
+            height: bounds.height,
+            area: bounds.area,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{f32::consts::SQRT_2, time::Duration};
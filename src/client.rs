@@ -1,67 +1,816 @@
-use std::{time::{Instant, Duration}, sync::{RwLock, Arc, Mutex}, collections::HashSet, cell::{RefCell, Cell}, thread};
+use std::{net::{IpAddr, SocketAddr}, time::{Instant, Duration}, sync::{atomic::{AtomicBool, Ordering}, mpsc::{channel, Receiver, Sender}, Arc, Condvar, Mutex, RwLock}, collections::{HashMap, HashSet, VecDeque}, thread::{self, JoinHandle}};
 
 use indexmap::IndexMap;
-use ringbuffer::{ConstGenericRingBuffer, RingBufferWrite, RingBufferRead};
-use rosc::{OscPacket};
+use rosc::{OscPacket, OscBundle, OscType};
+#[cfg(feature = "async")]
+use std::{pin::Pin, task::{Context, Poll, ready}};
+#[cfg(feature = "async")]
+use futures_core::Stream;
+#[cfg(feature = "async")]
+use tokio::{sync::mpsc::{self, UnboundedReceiver}, task};
+#[cfg(feature = "async")]
+use crate::osc_receiver::{AsyncOscReceiver, AsyncRoscReceiver, AsyncUdpReceiver};
 
-use crate::{osc_receiver::{UdpReceiver, RoscReceiver}, cursor::{Cursor}, object::Object, blob::Blob, errors::{TuioError, OscReceiverError}, osc_encode_decode::{OscDecoder, DecodeOsc, self, Set}};
+use crate::{osc_receiver::{OscReceiver, UdpReceiver, RoscReceiver, SocketOptions, DISCONNECT_POLL_INTERVAL}, cursor::{Cursor}, object::Object, blob::Blob, cursor_25d::Cursor25D, object_25d::Object25D, blob_25d::Blob25D, cursor_3d::Cursor3D, object_3d::Object3D, blob_3d::Blob3D, custom_profile::CustomProfile, errors::{TuioError, OscReceiverError}, osc_encode_decode::{OscDecoder, DecodeOsc, self, Set}, tuio2::{Token, Pointer, Bounds, Symbol, Geometry, Association, AssociationGraph, Tuio2Decoder, DecodeTuio2}};
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TuioEvents {
     pub cursor_events: Vec<CursorEvent>,
     pub object_events: Vec<ObjectEvent>,
     pub blob_events: Vec<BlobEvent>,
+    pub cursor_25d_events: Vec<Cursor25DEvent>,
+    pub object_25d_events: Vec<Object25DEvent>,
+    pub blob_25d_events: Vec<Blob25DEvent>,
+    pub cursor_3d_events: Vec<Cursor3DEvent>,
+    pub object_3d_events: Vec<Object3DEvent>,
+    pub blob_3d_events: Vec<Blob3DEvent>,
+    pub custom_events: Vec<CustomEvent>,
+    pub token_events: Vec<TokenEvent>,
+    pub pointer_events: Vec<PointerEvent>,
+    pub bounds_events: Vec<BoundsEvent>,
+    pub symbol_events: Vec<SymbolEvent>,
+    pub geometry_events: Vec<GeometryEvent>,
+    pub association_events: Vec<AssociationEvent>,
+    pub source_events: Vec<SourceEvent>,
+    /// The number of packets dropped by the [Client]'s buffer since the previous refresh
+    pub dropped_packets: usize,
+}
+
+/// A single event of any profile, as delivered by [Client::events]
+#[derive(Clone)]
+pub enum TuioEvent {
+    Cursor(CursorEvent),
+    Object(ObjectEvent),
+    Blob(BlobEvent),
+    Cursor25D(Cursor25DEvent),
+    Object25D(Object25DEvent),
+    Blob25D(Blob25DEvent),
+    Cursor3D(Cursor3DEvent),
+    Object3D(Object3DEvent),
+    Blob3D(Blob3DEvent),
+    Custom(CustomEvent),
+    Token(TokenEvent),
+    Pointer(PointerEvent),
+    Bounds(BoundsEvent),
+    Symbol(SymbolEvent),
+    Geometry(GeometryEvent),
+    Association(AssociationEvent),
+    Source(SourceEvent),
+    /// A number of packets dropped by the [Client]'s buffer
+    PacketsDropped(usize),
+}
+
+impl IntoIterator for TuioEvents {
+    type Item = TuioEvent;
+    type IntoIter = std::vec::IntoIter<TuioEvent>;
+
+    /// Returns the events as [TuioEvent]s, grouped by profile in the order of the [TuioEvents] fields
+    fn into_iter(self) -> Self::IntoIter {
+        self.cursor_events.into_iter().map(TuioEvent::Cursor)
+            .chain(self.object_events.into_iter().map(TuioEvent::Object))
+            .chain(self.blob_events.into_iter().map(TuioEvent::Blob))
+            .chain(self.cursor_25d_events.into_iter().map(TuioEvent::Cursor25D))
+            .chain(self.object_25d_events.into_iter().map(TuioEvent::Object25D))
+            .chain(self.blob_25d_events.into_iter().map(TuioEvent::Blob25D))
+            .chain(self.cursor_3d_events.into_iter().map(TuioEvent::Cursor3D))
+            .chain(self.object_3d_events.into_iter().map(TuioEvent::Object3D))
+            .chain(self.blob_3d_events.into_iter().map(TuioEvent::Blob3D))
+            .chain(self.custom_events.into_iter().map(TuioEvent::Custom))
+            .chain(self.token_events.into_iter().map(TuioEvent::Token))
+            .chain(self.pointer_events.into_iter().map(TuioEvent::Pointer))
+            .chain(self.bounds_events.into_iter().map(TuioEvent::Bounds))
+            .chain(self.symbol_events.into_iter().map(TuioEvent::Symbol))
+            .chain(self.geometry_events.into_iter().map(TuioEvent::Geometry))
+            .chain(self.association_events.into_iter().map(TuioEvent::Association))
+            .chain(self.source_events.into_iter().map(TuioEvent::Source))
+            .chain((self.dropped_packets > 0).then_some(TuioEvent::PacketsDropped(self.dropped_packets)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl TuioEvents {
+    /// Moves the events of another [TuioEvents] at the end of these ones
+    fn append(&mut self, mut other: TuioEvents) {
+        self.cursor_events.append(&mut other.cursor_events);
+        self.object_events.append(&mut other.object_events);
+        self.blob_events.append(&mut other.blob_events);
+        self.cursor_25d_events.append(&mut other.cursor_25d_events);
+        self.object_25d_events.append(&mut other.object_25d_events);
+        self.blob_25d_events.append(&mut other.blob_25d_events);
+        self.cursor_3d_events.append(&mut other.cursor_3d_events);
+        self.object_3d_events.append(&mut other.object_3d_events);
+        self.blob_3d_events.append(&mut other.blob_3d_events);
+        self.custom_events.append(&mut other.custom_events);
+        self.token_events.append(&mut other.token_events);
+        self.pointer_events.append(&mut other.pointer_events);
+        self.bounds_events.append(&mut other.bounds_events);
+        self.symbol_events.append(&mut other.symbol_events);
+        self.geometry_events.append(&mut other.geometry_events);
+        self.association_events.append(&mut other.association_events);
+        self.source_events.append(&mut other.source_events);
+        self.dropped_packets += other.dropped_packets;
+    }
+
+    /// Notifies a [TuioListener] of these events
+    fn notify(&self, listener: &dyn TuioListener) {
+        listener.events(self);
+
+        if self.dropped_packets > 0 {
+            listener.packets_dropped(self.dropped_packets);
+        }
+
+        for event in &self.cursor_events {
+            match event {
+                CursorEvent::New(data) => listener.add_cursor(data),
+                CursorEvent::Update(data) => listener.update_cursor(data),
+                CursorEvent::Remove(data) => listener.remove_cursor(data),
+            }
+        }
+
+        for event in &self.object_events {
+            match event {
+                ObjectEvent::New(data) => listener.add_object(data),
+                ObjectEvent::Update(data) => listener.update_object(data),
+                ObjectEvent::Remove(data) => listener.remove_object(data),
+            }
+        }
+
+        for event in &self.blob_events {
+            match event {
+                BlobEvent::New(data) => listener.add_blob(data),
+                BlobEvent::Update(data) => listener.update_blob(data),
+                BlobEvent::Remove(data) => listener.remove_blob(data),
+            }
+        }
+
+        for event in &self.token_events {
+            match event {
+                TokenEvent::New(data) => listener.add_token(data),
+                TokenEvent::Update(data) => listener.update_token(data),
+                TokenEvent::Remove(data) => listener.remove_token(data),
+            }
+        }
+
+        for event in &self.pointer_events {
+            match event {
+                PointerEvent::New(data) => listener.add_pointer(data),
+                PointerEvent::Update(data) => listener.update_pointer(data),
+                PointerEvent::Remove(data) => listener.remove_pointer(data),
+            }
+        }
+
+        for event in &self.bounds_events {
+            match event {
+                BoundsEvent::New(data) => listener.add_bounds(data),
+                BoundsEvent::Update(data) => listener.update_bounds(data),
+                BoundsEvent::Remove(data) => listener.remove_bounds(data),
+            }
+        }
+
+        for event in &self.symbol_events {
+            match event {
+                SymbolEvent::New(data) => listener.add_symbol(data),
+                SymbolEvent::Update(data) => listener.update_symbol(data),
+                SymbolEvent::Remove(data) => listener.remove_symbol(data),
+            }
+        }
+
+        for event in &self.source_events {
+            match event {
+                SourceEvent::New(data) => listener.add_source(data),
+                SourceEvent::Remove(data) => listener.remove_source(data),
+            }
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct CursorData {
     pub source_name: String,
+    pub protocol: Protocol,
     pub cursor: Cursor
 }
 
+#[derive(Clone)]
 pub struct ObjectData {
     pub source_name: String,
+    pub protocol: Protocol,
     pub object: Object
 }
 
+#[derive(Clone)]
 pub struct BlobData {
     pub source_name: String,
+    pub protocol: Protocol,
     pub blob: Blob
 }
 
+#[derive(Clone)]
+pub struct Cursor25DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub cursor: Cursor25D
+}
+
+#[derive(Clone)]
+pub struct Object25DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub object: Object25D
+}
+
+#[derive(Clone)]
+pub struct Blob25DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub blob: Blob25D
+}
+
+#[derive(Clone)]
+pub struct Cursor3DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub cursor: Cursor3D
+}
+
+#[derive(Clone)]
+pub struct Object3DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub object: Object3D
+}
+
+#[derive(Clone)]
+pub struct Blob3DData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub blob: Blob3D
+}
+
+#[derive(Clone)]
+pub struct CustomData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub custom: CustomProfile
+}
+
+#[derive(Clone)]
+pub struct TokenData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub token: Token
+}
+
+#[derive(Clone)]
+pub struct PointerData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub pointer: Pointer
+}
+
+#[derive(Clone)]
+pub struct BoundsData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub bounds: Bounds
+}
+
+#[derive(Clone)]
+pub struct SymbolData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub symbol: Symbol
+}
+
+#[derive(Clone)]
+pub struct GeometryData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub geometry: Geometry
+}
+
+#[derive(Clone)]
+pub struct AssociationData {
+    pub source_name: String,
+    pub protocol: Protocol,
+    pub association: Association
+}
+
+#[derive(Clone)]
+pub struct SourceData {
+    pub source_name: String,
+    pub info: SourceInfo,
+}
+
+#[derive(Clone)]
 pub enum CursorEvent {
     New(CursorData),
     Update(CursorData),
     Remove(CursorData),
 }
 
+#[derive(Clone)]
 pub enum ObjectEvent {
     New(ObjectData),
     Update(ObjectData),
     Remove(ObjectData),
 }
 
+#[derive(Clone)]
 pub enum BlobEvent {
     New(BlobData),
     Update(BlobData),
     Remove(BlobData),
 }
 
-#[derive(Default)]
+#[derive(Clone)]
+pub enum Cursor25DEvent {
+    New(Cursor25DData),
+    Update(Cursor25DData),
+    Remove(Cursor25DData),
+}
+
+#[derive(Clone)]
+pub enum Object25DEvent {
+    New(Object25DData),
+    Update(Object25DData),
+    Remove(Object25DData),
+}
+
+#[derive(Clone)]
+pub enum Blob25DEvent {
+    New(Blob25DData),
+    Update(Blob25DData),
+    Remove(Blob25DData),
+}
+
+#[derive(Clone)]
+pub enum Cursor3DEvent {
+    New(Cursor3DData),
+    Update(Cursor3DData),
+    Remove(Cursor3DData),
+}
+
+#[derive(Clone)]
+pub enum Object3DEvent {
+    New(Object3DData),
+    Update(Object3DData),
+    Remove(Object3DData),
+}
+
+#[derive(Clone)]
+pub enum Blob3DEvent {
+    New(Blob3DData),
+    Update(Blob3DData),
+    Remove(Blob3DData),
+}
+
+#[derive(Clone)]
+pub enum CustomEvent {
+    New(CustomData),
+    Update(CustomData),
+    Remove(CustomData),
+}
+
+#[derive(Clone)]
+pub enum TokenEvent {
+    New(TokenData),
+    Update(TokenData),
+    Remove(TokenData),
+}
+
+#[derive(Clone)]
+pub enum PointerEvent {
+    New(PointerData),
+    Update(PointerData),
+    Remove(PointerData),
+}
+
+#[derive(Clone)]
+pub enum BoundsEvent {
+    New(BoundsData),
+    Update(BoundsData),
+    Remove(BoundsData),
+}
+
+#[derive(Clone)]
+pub enum SymbolEvent {
+    New(SymbolData),
+    Update(SymbolData),
+    Remove(SymbolData),
+}
+
+#[derive(Clone)]
+pub enum GeometryEvent {
+    New(GeometryData),
+    Update(GeometryData),
+    Remove(GeometryData),
+}
+
+/// An [Association] change, only emitted when an association differs from the previous frame
+#[derive(Clone)]
+pub enum AssociationEvent {
+    New(AssociationData),
+    Update(AssociationData),
+    Remove(AssociationData),
+}
+
+/// A change of the sources known by a [Client]
+#[derive(Clone)]
+pub enum SourceEvent {
+    /// The first bundle of the source was received
+    New(SourceData),
+    /// The source timed out, or left every profile it sent with an end-of-session bundle, and was removed from the source list
+    Remove(SourceData),
+}
+
+/// Receives the events of a [Client], as the TuioListener of the reference TUIO implementations
+///
+/// Every method does nothing by default. The profiles without dedicated methods, such as the 2.5D and 3D ones,
+/// are reported through [TuioListener::events].
+pub trait TuioListener: Send + Sync {
+    /// Called with all the events of a frame, before the dedicated methods
+    fn events(&self, _events: &TuioEvents) {}
+
+    fn add_cursor(&self, _data: &CursorData) {}
+    fn update_cursor(&self, _data: &CursorData) {}
+    fn remove_cursor(&self, _data: &CursorData) {}
+
+    fn add_object(&self, _data: &ObjectData) {}
+    fn update_object(&self, _data: &ObjectData) {}
+    fn remove_object(&self, _data: &ObjectData) {}
+
+    fn add_blob(&self, _data: &BlobData) {}
+    fn update_blob(&self, _data: &BlobData) {}
+    fn remove_blob(&self, _data: &BlobData) {}
+
+    fn add_token(&self, _data: &TokenData) {}
+    fn update_token(&self, _data: &TokenData) {}
+    fn remove_token(&self, _data: &TokenData) {}
+
+    fn add_pointer(&self, _data: &PointerData) {}
+    fn update_pointer(&self, _data: &PointerData) {}
+    fn remove_pointer(&self, _data: &PointerData) {}
+
+    fn add_bounds(&self, _data: &BoundsData) {}
+    fn update_bounds(&self, _data: &BoundsData) {}
+    fn remove_bounds(&self, _data: &BoundsData) {}
+
+    fn add_symbol(&self, _data: &SymbolData) {}
+    fn update_symbol(&self, _data: &SymbolData) {}
+    fn remove_symbol(&self, _data: &SymbolData) {}
+
+    fn add_source(&self, _data: &SourceData) {}
+    fn remove_source(&self, _data: &SourceData) {}
+
+    /// Called when the [Client]'s buffer dropped packets, before the events of the following frames
+    ///
+    /// # Arguments
+    /// * `count` - the number of dropped packets
+    fn packets_dropped(&self, _count: usize) {}
+
+    /// Called when a received packet fails to be processed, the other packets being processed normally
+    ///
+    /// # Arguments
+    /// * `error` - the [TuioError] of the packet
+    fn packet_error(&self, _error: &TuioError) {}
+
+    /// Called after the events of each frame
+    ///
+    /// # Arguments
+    /// * `frame_time` - the time elapsed between the [Client]'s creation and the frame's processing
+    fn refresh(&self, _frame_time: Duration) {}
+}
+
+/// Where the [TuioListener]s of a [Client] are notified
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ListenerDispatch {
+    /// Packets are buffered until [Client::refresh], which notifies the listeners on the calling thread
+    #[default]
+    Refresh,
+    /// Packets are processed as soon as they are received, notifying the listeners on the receiving threads
+    ReceiveThread,
+}
+
+/// The metadata of a source, updated by each of its bundles
+#[derive(Debug, Default, Clone)]
+pub struct SourceInfo {
+    name: String,
+    host: Option<String>,
+    address: Option<IpAddr>,
+    protocol: Protocol,
+    first_seen: Duration,
+    last_seen: Duration,
+    frame_rate: f32,
+    /// The time and frame starting the current frame rate measure
+    rate_window: (Duration, i32),
+}
+
+impl SourceInfo {
+    /// Creates a [SourceInfo] from a TUIO source string, such as `name@host` in TUIO 1.1 or `name:instance@host` in TUIO 2.0
+    ///
+    /// # Arguments
+    /// * `source` - the source string
+    /// * `protocol` - the [Protocol] of the source's bundles
+    /// * `time` - the time the source was first seen
+    fn new(source: &str, protocol: Protocol, time: Duration) -> Self {
+        let (name, host) = match source.split_once('@') {
+            Some((name, host)) => (name, Some(host.to_string())),
+            None => (source, None),
+        };
+
+        Self {
+            name: name.split(':').next().unwrap_or_default().to_string(),
+            address: host.as_deref().and_then(|host| host.parse().ok()),
+            host,
+            protocol,
+            first_seen: time,
+            last_seen: time,
+            frame_rate: 0.,
+            rate_window: (time, -1),
+        }
+    }
+
+    /// Updates the last seen time and the frame rate, measured over one second windows
+    ///
+    /// # Arguments
+    /// * `protocol` - the [Protocol] of the bundle
+    /// * `frame` - the frame of the bundle
+    /// * `time` - the time the bundle was processed
+    fn update(&mut self, protocol: Protocol, frame: i32, time: Duration) {
+        self.protocol = protocol;
+        self.last_seen = time;
+
+        let (window_time, window_frame) = self.rate_window;
+
+        if frame < 0 {
+            return;
+        }
+
+        if window_frame < 0 || frame < window_frame {
+            self.rate_window = (time, frame);
+        }
+        else if time - window_time >= Duration::from_secs(1) {
+            self.frame_rate = (frame - window_frame) as f32 / (time - window_time).as_secs_f32();
+            self.rate_window = (time, frame);
+        }
+    }
+
+    /// Returns the source name, without its instance and host
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the host following the `@` of the source string, such as `local` for a [crate::Server] on a local address
+    pub fn get_host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Returns the host as an [IpAddr], if it is one
+    pub fn get_address(&self) -> Option<IpAddr> {
+        self.address
+    }
+
+    /// Returns the [Protocol] of the last bundle
+    pub fn get_protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Returns the time elapsed between the [Client]'s creation and the source's first bundle
+    pub fn get_first_seen(&self) -> Duration {
+        self.first_seen
+    }
+
+    /// Returns the time elapsed between the [Client]'s creation and the source's last bundle
+    pub fn get_last_seen(&self) -> Duration {
+        self.last_seen
+    }
+
+    /// Returns the frames per second of the source, measured on the last second, or 0 before the first measure
+    pub fn get_frame_rate(&self) -> f32 {
+        self.frame_rate
+    }
+}
+
+#[derive(Default, Clone)]
 pub struct SourceCollection {
+    pub info: SourceInfo,
     pub object_map: IndexMap<i32, Object>,
     pub blob_map: IndexMap<i32, Blob>,
-    pub cursor_map: IndexMap<i32, Cursor>
+    pub cursor_map: IndexMap<i32, Cursor>,
+    pub object_25d_map: IndexMap<i32, Object25D>,
+    pub blob_25d_map: IndexMap<i32, Blob25D>,
+    pub cursor_25d_map: IndexMap<i32, Cursor25D>,
+    pub object_3d_map: IndexMap<i32, Object3D>,
+    pub blob_3d_map: IndexMap<i32, Blob3D>,
+    pub cursor_3d_map: IndexMap<i32, Cursor3D>,
+    /// Custom profile entities, keyed by format string then by session id
+    pub custom_map: IndexMap<String, IndexMap<i32, CustomProfile>>,
+    pub token_map: IndexMap<i32, Token>,
+    pub pointer_map: IndexMap<i32, Pointer>,
+    pub bounds_map: IndexMap<i32, Bounds>,
+    pub symbol_map: IndexMap<i32, Symbol>,
+    /// Geometries attached to the session ids of the other TUIO 2 components
+    pub geometry_map: IndexMap<i32, Geometry>,
+    /// Container and link associations between the session ids of the TUIO 2 components
+    pub association_graph: AssociationGraph,
+}
+
+/// The TUIO protocol version spoken by a [Client]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// TUIO 1.1, with one bundle per profile such as `/tuio/2Dcur`
+    #[default]
+    Tuio1,
+    /// TUIO 2.0, with one `/tuio2/frm` bundle per frame
+    Tuio2,
+}
+
+impl Protocol {
+    /// Detects the [Protocol] of a bundle from the address of its first message
+    ///
+    /// Returns [None] if the bundle contains neither `/tuio/` nor `/tuio2/` messages
+    /// # Arguments
+    /// * `bundle` - the [OscBundle] to inspect
+    pub fn detect(bundle: &OscBundle) -> Option<Self> {
+        bundle.content.iter().find_map(|packet| match packet {
+            OscPacket::Message(message) if message.addr.starts_with("/tuio2/") => Some(Self::Tuio2),
+            OscPacket::Message(message) if message.addr.starts_with("/tuio/") => Some(Self::Tuio1),
+            _ => None
+        })
+    }
+}
+
+/// The connection state of a [Client]'s receivers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Disconnected,
+    Connected,
+    /// Connected, but at least one receiver stopped on an error, such as a closed TCP connection
+    Interrupted,
+}
+
+/// The last frame received from a source for a profile, used to discard late bundles
+#[derive(Default)]
+struct FrameTracker {
+    frame: i32,
+    /// When the frame was accepted, since the creation of the [Client]
+    time: Duration,
+}
+
+/// The time after which an older frame is accepted, as the source most likely restarted its sequence
+const LATE_FRAME_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Identifies a [FrameTracker] by source name and TUIO 1.1 profile, [None] standing for TUIO 2.0 frames
+type FrameKey = (String, Option<osc_encode_decode::TuioBundleType>);
+
+/// How a [Client] buffers the packets received between two refreshes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferPolicy {
+    /// Keeps up to a number of packets, dropping the oldest ones
+    DropOldest(usize),
+    /// Keeps up to a number of packets, only keeping the latest TUIO 1.1 bundle of each source's profile once full,
+    /// then dropping the oldest ones
+    ///
+    /// TUIO 2.0 frames only carry their changed components, so they are never coalesced. A TUIO 1.1 entity updated in
+    /// a dropped bundle only gets its latest update, and misses it until its next update if the server does not send
+    /// full updates
+    Coalesce(usize),
+    /// Keeps up to a number of packets, the receiving threads waiting for the next refresh once full
+    ///
+    /// Stream transports such as TCP then slow the sender down, while datagrams are dropped by the system
+    Backpressure(usize),
+    /// Keeps every packet
+    Unbounded,
+}
+
+impl Default for BufferPolicy {
+    fn default() -> Self {
+        Self::DropOldest(128)
+    }
+}
+
+/// The packets received between two refreshes, bounded by a [BufferPolicy]
+#[derive(Default)]
+struct PacketBuffer {
+    packets: VecDeque<OscPacket>,
+    policy: BufferPolicy,
+    /// The packets dropped since the last drain
+    dropped: usize,
+    /// The packets dropped since the [Client]'s creation
+    total_dropped: u64,
+    /// The errors of the packets which failed to be decoded since the last drain
+    errors: Vec<TuioError>,
+}
+
+impl PacketBuffer {
+    fn is_empty(&self) -> bool {
+        self.packets.is_empty() && self.errors.is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        match self.policy {
+            BufferPolicy::DropOldest(capacity) | BufferPolicy::Coalesce(capacity) | BufferPolicy::Backpressure(capacity) => self.packets.len() >= capacity,
+            BufferPolicy::Unbounded => false,
+        }
+    }
+
+    /// Returns true if a receiving thread must wait for a refresh before pushing
+    fn must_wait(&self) -> bool {
+        matches!(self.policy, BufferPolicy::Backpressure(_)) && self.is_full()
+    }
+
+    /// Pushes a packet, dropping packets as required by the [BufferPolicy] if the buffer is full
+    ///
+    /// # Arguments
+    /// * `packet` - the received packet
+    fn push(&mut self, packet: OscPacket) {
+        if matches!(self.policy, BufferPolicy::Coalesce(_)) && self.is_full() {
+            self.coalesce();
+        }
+
+        while self.is_full() && self.packets.pop_front().is_some() {
+            self.dropped += 1;
+            self.total_dropped += 1;
+        }
+
+        self.packets.push_back(packet);
+    }
+
+    /// Drops the bundles followed by a bundle of the same source and profile
+    fn coalesce(&mut self) {
+        let mut latest = HashSet::new();
+        let mut kept = VecDeque::with_capacity(self.packets.len());
+
+        for packet in self.packets.drain(..).rev() {
+            if coalescing_key(&packet).is_some_and(|key| !latest.insert(key)) {
+                self.dropped += 1;
+                self.total_dropped += 1;
+            }
+            else {
+                kept.push_front(packet);
+            }
+        }
+
+        self.packets = kept;
+    }
+
+    /// Returns the buffered packets, the number of packets dropped and the decoding errors since the last drain
+    fn drain(&mut self) -> (Vec<OscPacket>, usize, Vec<TuioError>) {
+        (self.packets.drain(..).collect(), std::mem::take(&mut self.dropped), std::mem::take(&mut self.errors))
+    }
+}
+
+/// Returns the profile address and source of a TUIO 1.1 bundle, without decoding it
+///
+/// TUIO 2.0 frames have no key, as dropping one loses the components it created or changed
+fn coalescing_key(packet: &OscPacket) -> Option<(String, String)> {
+    let OscPacket::Bundle(bundle) = packet else {
+        return None;
+    };
+
+    let mut address = None;
+    let mut source = String::new();
+
+    for content in &bundle.content {
+        if let OscPacket::Message(message) = content {
+            address.get_or_insert_with(|| message.addr.clone());
+
+            match (message.addr.as_str(), &message.args[..]) {
+                ("/tuio2/frm", _) => return None,
+                (_, [OscType::String(command), OscType::String(name)]) if command == "source" => source = name.clone(),
+                _ => {},
+            }
+        }
+    }
+
+    address.map(|address| (address, source))
 }
 
+/// An immutable copy of a [Client]'s state, swapped after each refresh changing it
+#[derive(Default, Clone)]
+pub struct ClientSnapshot {
+    pub source_list: IndexMap<String, SourceCollection>,
+}
+
+/// A TUIO client, which can be shared between threads
+///
+/// Refreshing locks [Client::source_list] while packets are processed. Readers which should not wait for it can
+/// hold the [ClientSnapshot] returned by [Client::snapshot] instead.
 pub struct Client {
-    current_frame: Cell<i32>,
-    instant: Instant,
-    current_time: Cell<Duration>,
-    pub source_list: RefCell<IndexMap<String, SourceCollection>>,
+    state: ClientState,
+    pub source_list: Arc<RwLock<IndexMap<String, SourceCollection>>>,
     osc_receivers: Vec<Arc<RoscReceiver>>,
-    packet_buffer: Arc<Mutex<ConstGenericRingBuffer<OscPacket, 128>>>,
+    packet_buffer: Arc<Mutex<PacketBuffer>>,
+    /// Notified by the receiving threads after buffering a packet
+    packet_signal: Arc<Condvar>,
+    /// Notified after draining the buffer, for the receiving threads waiting with [BufferPolicy::Backpressure]
+    space_signal: Arc<Condvar>,
     local_receiver: bool,
-    listen: Arc<RwLock<bool>>
+    listen: Arc<AtomicBool>,
+    receive_threads: Mutex<Vec<JoinHandle<()>>>,
+    listener_dispatch: ListenerDispatch,
 }
 
 /// Keeps the entries whose keys are contained in a [HashSet]
@@ -71,7 +820,7 @@ pub struct Client {
 /// # Arguments
 /// * `index_map` - an [IndexMap<i32, T>] to filter
 /// * `to_keep` - an [HashSet<i32>] containing the keys to retain
-fn retain_by_ids<T>(index_map: &mut IndexMap<i32, T>, to_keep: HashSet<i32>) -> Vec<T> {
+fn retain_by_ids<T>(index_map: &mut IndexMap<i32, T>, to_keep: &HashSet<i32>) -> Vec<T> {
     let mut removed: Vec<T> = Vec::with_capacity(index_map.len());
     let mut to_remove: Vec<i32> = Vec::with_capacity(index_map.len());
 
@@ -88,206 +837,1822 @@ fn retain_by_ids<T>(index_map: &mut IndexMap<i32, T>, to_keep: HashSet<i32>) ->
     removed
 }
 
+/// A change applied to an entry of a [SourceCollection] map
+enum EntityChange<T> {
+    New(T),
+    Update(T),
+    Remove(T),
+}
+
+/// Applies an alive list and a set collection to an entity map
+///
+/// Returns the [EntityChange]s in the order they were applied: removals first, then new and updated entities
+///
+/// # Arguments
+/// * `entity_map` - an [IndexMap<i32, T>] to update
+/// * `to_keep` - an [HashSet<i32>] containing the alive ids
+/// * `set` - the entities of the "set" messages
+/// * `session_id` - a function returning an entity's session id
+fn apply_set<T: Clone>(entity_map: &mut IndexMap<i32, T>, to_keep: &HashSet<i32>, set: Vec<T>, session_id: fn(&T) -> i32) -> Vec<EntityChange<T>> {
+    let mut changes: Vec<EntityChange<T>> = retain_by_ids(entity_map, to_keep).into_iter().map(EntityChange::Remove).collect();
+
+    for entity in set {
+        match entity_map.entry(session_id(&entity)) {
+            indexmap::map::Entry::Occupied(mut entry) => {
+                changes.push(EntityChange::Update(entity.clone()));
+                entry.insert(entity);
+            },
+            indexmap::map::Entry::Vacant(entry) => {
+                changes.push(EntityChange::New(entity.clone()));
+                entry.insert(entity);
+            },
+        }
+    }
+
+    changes
+}
+
+/// Applies an alive list and tokens to a [SourceCollection], pushing the resulting [TokenEvent]s
+fn apply_tokens(source_collection: &mut SourceCollection, to_keep: &HashSet<i32>, tokens: Vec<Token>, source_name: &str, protocol: Protocol, events: &mut TuioEvents) {
+    for change in apply_set(&mut source_collection.token_map, to_keep, tokens, Token::get_session_id) {
+        events.token_events.push(match change {
+            EntityChange::New(token) => TokenEvent::New(TokenData { source_name: source_name.into(), protocol, token }),
+            EntityChange::Update(token) => TokenEvent::Update(TokenData { source_name: source_name.into(), protocol, token }),
+            EntityChange::Remove(token) => TokenEvent::Remove(TokenData { source_name: source_name.into(), protocol, token }),
+        });
+    }
+}
+
+/// Applies an alive list and pointers to a [SourceCollection], pushing the resulting [PointerEvent]s
+fn apply_pointers(source_collection: &mut SourceCollection, to_keep: &HashSet<i32>, pointers: Vec<Pointer>, source_name: &str, protocol: Protocol, events: &mut TuioEvents) {
+    for change in apply_set(&mut source_collection.pointer_map, to_keep, pointers, Pointer::get_session_id) {
+        events.pointer_events.push(match change {
+            EntityChange::New(pointer) => PointerEvent::New(PointerData { source_name: source_name.into(), protocol, pointer }),
+            EntityChange::Update(pointer) => PointerEvent::Update(PointerData { source_name: source_name.into(), protocol, pointer }),
+            EntityChange::Remove(pointer) => PointerEvent::Remove(PointerData { source_name: source_name.into(), protocol, pointer }),
+        });
+    }
+}
+
+/// Applies an alive list and bounds to a [SourceCollection], pushing the resulting [BoundsEvent]s
+fn apply_bounds(source_collection: &mut SourceCollection, to_keep: &HashSet<i32>, bounds: Vec<Bounds>, source_name: &str, protocol: Protocol, events: &mut TuioEvents) {
+    for change in apply_set(&mut source_collection.bounds_map, to_keep, bounds, Bounds::get_session_id) {
+        events.bounds_events.push(match change {
+            EntityChange::New(bounds) => BoundsEvent::New(BoundsData { source_name: source_name.into(), protocol, bounds }),
+            EntityChange::Update(bounds) => BoundsEvent::Update(BoundsData { source_name: source_name.into(), protocol, bounds }),
+            EntityChange::Remove(bounds) => BoundsEvent::Remove(BoundsData { source_name: source_name.into(), protocol, bounds }),
+        });
+    }
+}
+
+/// Pushes a Remove event for every entity of a removed [SourceCollection], then its [SourceEvent::Remove]
+fn clear_source(source_collection: SourceCollection, source_name: &str, events: &mut TuioEvents) {
+    let protocol = source_collection.info.protocol;
+
+    events.cursor_events.extend(source_collection.cursor_map.into_values().map(|cursor| CursorEvent::Remove(CursorData { source_name: source_name.into(), protocol: Protocol::Tuio1, cursor })));
+    events.object_events.extend(source_collection.object_map.into_values().map(|object| ObjectEvent::Remove(ObjectData { source_name: source_name.into(), protocol: Protocol::Tuio1, object })));
+    events.blob_events.extend(source_collection.blob_map.into_values().map(|blob| BlobEvent::Remove(BlobData { source_name: source_name.into(), protocol: Protocol::Tuio1, blob })));
+    events.cursor_25d_events.extend(source_collection.cursor_25d_map.into_values().map(|cursor| Cursor25DEvent::Remove(Cursor25DData { source_name: source_name.into(), protocol: Protocol::Tuio1, cursor })));
+    events.object_25d_events.extend(source_collection.object_25d_map.into_values().map(|object| Object25DEvent::Remove(Object25DData { source_name: source_name.into(), protocol: Protocol::Tuio1, object })));
+    events.blob_25d_events.extend(source_collection.blob_25d_map.into_values().map(|blob| Blob25DEvent::Remove(Blob25DData { source_name: source_name.into(), protocol: Protocol::Tuio1, blob })));
+    events.cursor_3d_events.extend(source_collection.cursor_3d_map.into_values().map(|cursor| Cursor3DEvent::Remove(Cursor3DData { source_name: source_name.into(), protocol: Protocol::Tuio1, cursor })));
+    events.object_3d_events.extend(source_collection.object_3d_map.into_values().map(|object| Object3DEvent::Remove(Object3DData { source_name: source_name.into(), protocol: Protocol::Tuio1, object })));
+    events.blob_3d_events.extend(source_collection.blob_3d_map.into_values().map(|blob| Blob3DEvent::Remove(Blob3DData { source_name: source_name.into(), protocol: Protocol::Tuio1, blob })));
+    events.custom_events.extend(source_collection.custom_map.into_values().flat_map(IndexMap::into_values).map(|custom| CustomEvent::Remove(CustomData { source_name: source_name.into(), protocol: Protocol::Tuio1, custom })));
+    events.token_events.extend(source_collection.token_map.into_values().map(|token| TokenEvent::Remove(TokenData { source_name: source_name.into(), protocol, token })));
+    events.pointer_events.extend(source_collection.pointer_map.into_values().map(|pointer| PointerEvent::Remove(PointerData { source_name: source_name.into(), protocol, pointer })));
+    events.bounds_events.extend(source_collection.bounds_map.into_values().map(|bounds| BoundsEvent::Remove(BoundsData { source_name: source_name.into(), protocol, bounds })));
+    events.symbol_events.extend(source_collection.symbol_map.into_values().map(|symbol| SymbolEvent::Remove(SymbolData { source_name: source_name.into(), protocol: Protocol::Tuio2, symbol })));
+    events.geometry_events.extend(source_collection.geometry_map.into_values().map(|geometry| GeometryEvent::Remove(GeometryData { source_name: source_name.into(), protocol: Protocol::Tuio2, geometry })));
+
+    let mut association_graph = source_collection.association_graph;
+    events.association_events.extend(association_graph.retain(&HashSet::new()).into_iter().map(|association| AssociationEvent::Remove(AssociationData { source_name: source_name.into(), protocol: Protocol::Tuio2, association })));
+
+    events.source_events.push(SourceEvent::Remove(SourceData { source_name: source_name.into(), info: source_collection.info }));
+}
+
 impl Client {
     pub fn new() -> Result<Self, std::io::Error> {
         Self::from_port(3333)
     }
 
     pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
-        Ok(Self {
-            instant: Instant::now(),
-            osc_receivers: vec![Arc::new(UdpReceiver::from_port(port)?)],
-            current_frame: (-1).into(),
-            current_time: Cell::new(Duration::default()),
-            source_list: RefCell::new(IndexMap::new()),
-            local_receiver: true,
-            listen: Arc::new(RwLock::new(false)),
-            packet_buffer: Default::default()
-        })
+        let mut client = Self::from_osc_receiver(UdpReceiver::from_port(port)?);
+        client.local_receiver = true;
+        Ok(client)
     }
 
-    pub fn connect(&self) -> Result<(), OscReceiverError> {
-        if *self.listen.read().unwrap() {
-            return Err(OscReceiverError::AlreadyConnected());
-        }
+    /// Creates a [Client] with an [UdpReceiver] binded to the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - a socket address such as `0.0.0.0:3333` to receive from other machines
+    pub fn from_socket_addr(address: SocketAddr) -> Result<Self, std::io::Error> {
+        let mut client = Self::from_osc_receiver(UdpReceiver::from_socket_addr(address)?);
+        client.local_receiver = address.ip().is_loopback();
+        Ok(client)
+    }
 
-        *self.listen.write().unwrap() = true;
+    /// Creates a [Client] with an [UdpReceiver] binded to the provided socket address with [SocketOptions]
+    ///
+    /// # Arguments
+    /// * `address` - the socket address to bind
+    /// * `options` - the [SocketOptions], such as SO_REUSEADDR to share the port with other applications
+    pub fn from_socket_addr_with_options(address: SocketAddr, options: SocketOptions) -> Result<Self, std::io::Error> {
+        let mut client = Self::from_osc_receiver(UdpReceiver::with_options(address, options)?);
+        client.local_receiver = address.ip().is_loopback();
+        Ok(client)
+    }
 
-        for receiver in &self.osc_receivers {
-            receiver.connect().map_err(OscReceiverError::Connect)?;
+    /// Creates a [Client] from an OSC receiver implementing [OscReceiver] trait
+    ///
+    /// # Arguments
+    /// * `osc_receiver` - a receiver implementing [OscReceiver]
+    pub fn from_osc_receiver(osc_receiver: impl OscReceiver<OscPacket, OscReceiverError> + Send + Sync + 'static) -> Self {
+        Self::from_receiver_list(vec![Arc::new(osc_receiver)])
+    }
 
-            let listen = Arc::clone(&self.listen);
-            let receiver = Arc::clone(receiver);
-            let buffer = Arc::clone(&self.packet_buffer);
+    fn from_receiver_list(osc_receivers: Vec<Arc<RoscReceiver>>) -> Self {
+        let source_list = Arc::new(RwLock::new(IndexMap::new()));
 
-            thread::spawn(move || loop {
-                if !*listen.read().unwrap() {
-                    break;
-                }
-                
-                match receiver.recv() {
-                    Ok(packet) => {
-                        buffer.lock().unwrap().push(packet);
-                    }
-                    Err(err) => {
-                        match err {
-                            OscReceiverError::Receive(err) => if err.raw_os_error().unwrap() != 10004 {
-                                println!("Error receiving from socket: {}", err);
-                            },
-                            _ => println!("Error receiving from socket: {}", err)
-                        }
-                        
-                        break;
-                    }
-                }
-            });
+        Self {
+            state: ClientState {
+                instant: Instant::now(),
+                frame_trackers: Default::default(),
+                source_list: Arc::clone(&source_list),
+                snapshot: Default::default(),
+                listeners: Default::default(),
+                event_senders: Default::default(),
+                source_timeout: None,
+                errors: Default::default(),
+                protocol: Some(Protocol::default()),
+            },
+            source_list,
+            osc_receivers,
+            local_receiver: false,
+            listen: Arc::new(AtomicBool::new(false)),
+            receive_threads: Mutex::new(Vec::new()),
+            packet_buffer: Default::default(),
+            packet_signal: Default::default(),
+            space_signal: Default::default(),
+            listener_dispatch: ListenerDispatch::default(),
         }
+    }
 
-        Ok(())
+    /// Adds an OSC receiver implementing [OscReceiver] trait
+    ///
+    /// # Arguments
+    /// * `osc_receiver` - a receiver implementing [OscReceiver]
+    pub fn add_osc_receiver(&mut self, osc_receiver: impl OscReceiver<OscPacket, OscReceiverError> + Send + Sync + 'static) {
+        self.osc_receivers.push(Arc::new(osc_receiver));
     }
 
-    pub fn disconnect(&self) {
+    /// Returns this [Client] decoding the given [Protocol]
+    ///
+    /// # Arguments
+    /// * `protocol` - the TUIO [Protocol] version to decode
+    pub fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.state.protocol = Some(protocol);
+        self
+    }
+
+    /// Returns this [Client] buffering packets with a [BufferPolicy], [BufferPolicy::DropOldest] with a capacity of 128 by default
+    ///
+    /// # Arguments
+    /// * `policy` - the [BufferPolicy] applied when packets are received faster than refreshed
+    ///
+    /// # Panics
+    /// Panics if the capacity of `policy` is 0
+    pub fn with_buffer_policy(self, policy: BufferPolicy) -> Self {
+        if let BufferPolicy::DropOldest(capacity) | BufferPolicy::Coalesce(capacity) | BufferPolicy::Backpressure(capacity) = policy {
+            assert!(capacity > 0, "the capacity of a buffer policy must be at least 1, got {:?}", policy);
+        }
+
+        self.packet_buffer.lock().unwrap().policy = policy;
+        self
+    }
+
+    /// Returns the errors of the packets which failed to be processed since the last call, up to the last 128 errors
+    ///
+    /// A malformed packet does not prevent the processing of the other packets of a refresh
+    pub fn take_errors(&self) -> Vec<TuioError> {
+        std::mem::take(&mut self.state.errors.lock().unwrap())
+    }
+
+    /// Returns the number of packets dropped by the buffer since the [Client]'s creation
+    pub fn get_dropped_packets(&self) -> u64 {
+        self.packet_buffer.lock().unwrap().total_dropped
+    }
+
+    /// Returns this [Client] removing the sources which sent no bundle for a duration
+    ///
+    /// The entities of a removed source get Remove events, followed by a [SourceEvent::Remove]. Timeouts are checked on
+    /// each [Client::refresh], which can be called without received packets, such as with [ListenerDispatch::ReceiveThread]
+    /// # Arguments
+    /// * `timeout` - the inactivity duration after which a source is removed
+    pub fn with_source_timeout(mut self, timeout: Duration) -> Self {
+        self.state.source_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns this [Client] accepting both TUIO 1.1 and TUIO 2.0, detecting the [Protocol] of each bundle
+    ///
+    /// TUIO 1.1 cursors, objects and blobs are normalised into the TUIO 2 model and reported as
+    /// pointer, token and bounds events tagged with [Protocol::Tuio1]. The 2.5D, 3D and custom
+    /// TUIO 1.1 profiles have no TUIO 2 counterpart in this crate and are still reported through
+    /// their own maps and events.
+    pub fn with_dual_stack(mut self) -> Self {
+        self.state.protocol = None;
+        self
+    }
+
+    /// Returns the decoded [Protocol], or [None] for a dual-stack [Client]
+    pub fn get_protocol(&self) -> Option<Protocol> {
+        self.state.protocol
+    }
+
+    /// Returns this [Client] notifying its [TuioListener]s as set by a [ListenerDispatch]
+    ///
+    /// # Arguments
+    /// * `listener_dispatch` - where the listeners are notified, taking effect on the next [Client::connect]
+    pub fn with_listener_dispatch(mut self, listener_dispatch: ListenerDispatch) -> Self {
+        self.listener_dispatch = listener_dispatch;
+        self
+    }
+
+    /// Adds a [TuioListener] notified of the events of each frame
+    ///
+    /// # Arguments
+    /// * `listener` - the [TuioListener] to add
+    pub fn add_listener(&self, listener: Arc<dyn TuioListener>) {
+        self.state.listeners.write().unwrap().push(listener);
+    }
+
+    /// Removes a [TuioListener] previously added with [Client::add_listener]
+    ///
+    /// # Arguments
+    /// * `listener` - the added [TuioListener]
+    pub fn remove_listener(&self, listener: &Arc<dyn TuioListener>) {
+        self.state.listeners.write().unwrap().retain(|added_listener| !Arc::ptr_eq(added_listener, listener));
+    }
+
+    /// Connects the receivers, spawning one thread per receiver
+    ///
+    /// Returns [OscReceiverError::AlreadyConnected] unless disconnected, and disconnects the
+    /// already connected receivers if one of them fails to connect
+    pub fn connect(&self) -> Result<(), OscReceiverError> {
+        if self.listen.swap(true, Ordering::SeqCst) {
+            return Err(OscReceiverError::AlreadyConnected());
+        }
+
+        let mut receive_threads = self.receive_threads.lock().unwrap();
+
+        for receiver in &self.osc_receivers {
+            if let Err(err) = receiver.connect() {
+                drop(receive_threads);
+                self.disconnect();
+                return Err(OscReceiverError::Connect(err));
+            }
+
+            let listen = Arc::clone(&self.listen);
+            let receiver = Arc::clone(receiver);
+            let buffer = Arc::clone(&self.packet_buffer);
+            let packet_signal = Arc::clone(&self.packet_signal);
+            let space_signal = Arc::clone(&self.space_signal);
+            let state = (self.listener_dispatch == ListenerDispatch::ReceiveThread).then(|| self.state.clone());
+
+            receive_threads.push(thread::spawn(move || while listen.load(Ordering::SeqCst) {
+                match receiver.recv() {
+                    Ok(packet) => match &state {
+                        Some(state) => {
+                            state.process_osc_packets([packet], 0, Vec::new());
+                        },
+                        None => {
+                            let mut buffer = buffer.lock().unwrap();
+
+                            while buffer.must_wait() && listen.load(Ordering::SeqCst) {
+                                buffer = space_signal.wait_timeout(buffer, DISCONNECT_POLL_INTERVAL).unwrap().0;
+                            }
+
+                            buffer.push(packet);
+                            drop(buffer);
+                            packet_signal.notify_all();
+                        },
+                    },
+                    // A malformed packet should not stop the reception, its error being reported as on refresh
+                    Err(OscReceiverError::Decode(err)) => match &state {
+                        Some(state) => {
+                            state.process_osc_packets([], 0, vec![TuioError::Decode(err)]);
+                        },
+                        None => {
+                            buffer.lock().unwrap().errors.push(TuioError::Decode(err));
+                            packet_signal.notify_all();
+                        },
+                    },
+                    // The stopped thread is reported as ConnectionState::Interrupted
+                    Err(_) => break,
+                }
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Disconnects the receivers and joins their threads
+    ///
+    /// Blocks until every receiver returns from [OscReceiver::recv], which takes up to 100 ms for an [UdpReceiver]
+    pub fn disconnect(&self) {
+        self.listen.store(false, Ordering::SeqCst);
+
         for receiver in &self.osc_receivers {
             receiver.disconnect();
         }
+
+        for receive_thread in self.receive_threads.lock().unwrap().drain(..) {
+            let _ = receive_thread.join();
+        }
+    }
+
+    /// Disconnects then connects the receivers again, such as after a [ConnectionState::Interrupted]
+    pub fn reconnect(&self) -> Result<(), OscReceiverError> {
+        self.disconnect();
+        self.connect()
+    }
+
+    /// Returns the [ConnectionState] of the receivers
+    pub fn get_connection_state(&self) -> ConnectionState {
+        if !self.listen.load(Ordering::SeqCst) {
+            ConnectionState::Disconnected
+        }
+        else if self.receive_threads.lock().unwrap().iter().any(JoinHandle::is_finished) {
+            ConnectionState::Interrupted
+        }
+        else {
+            ConnectionState::Connected
+        }
     }
 
     /// Refreshes the client's state
     /// 
-    /// On success, returns an [Option] containing the evnts of all new, updated and removed TUIO inputs
-    pub fn refresh(&self) -> Result<Option<TuioEvents>, TuioError> {
-        let mut updated = false;
-        let mut events = TuioEvents::default();
+    /// Returns an [Option] containing the events of all new, updated and removed TUIO inputs. Every buffered packet is
+    /// processed, the errors of malformed packets being available through [Client::take_errors]
+    pub fn refresh(&self) -> Option<TuioEvents> {
+        let (packets, dropped_packets, errors) = self.packet_buffer.lock().unwrap().drain();
+        self.space_signal.notify_all();
+        self.state.process_osc_packets(packets, dropped_packets, errors)
+    }
 
-        for packet in self.packet_buffer.lock().unwrap().drain() {
-            if self.process_osc_packet(packet, &mut events)? {
-                updated = true;
+    /// Blocks until a received packet changes the state or the timeout expires, then refreshes the client's state
+    ///
+    /// Returns [None] on timeout, unless sources timed out. With [ListenerDispatch::ReceiveThread], packets are not buffered and this always
+    /// waits for the timeout, the events being delivered to the listeners and to [Client::events] instead
+    /// # Arguments
+    /// * `timeout` - the maximum duration to wait for
+    pub fn wait_for_events(&self, timeout: Duration) -> Option<TuioEvents> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let mut buffer = self.packet_buffer.lock().unwrap();
+
+            while buffer.is_empty() {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                // Applies the source timeouts
+                if remaining.is_zero() {
+                    drop(buffer);
+                    return self.refresh();
+                }
+
+                buffer = self.packet_signal.wait_timeout(buffer, remaining).unwrap().0;
             }
-        };
 
-        if updated {
-            Ok(Some(events))
+            drop(buffer);
+
+            // Late frames do not change the state, so waiting goes on
+            if let Some(events) = self.refresh() {
+                return Some(events);
+            }
         }
-        else {
-            Ok(None)
+    }
+
+    /// Returns a [Receiver] of every [TuioEvent] processed from now on, in order
+    ///
+    /// Events are sent as packets are processed, by [Client::refresh] or by the receiving threads with
+    /// [ListenerDispatch::ReceiveThread]. Dropping the [Receiver] unregisters it.
+    ///
+    /// Listeners can subscribe from their callbacks, the [Receiver] then getting the events of the notified frame
+    pub fn events(&self) -> Receiver<TuioEvent> {
+        let (sender, receiver) = channel();
+        self.state.event_senders.lock().unwrap().push(sender);
+        receiver
+    }
+
+    /// Returns the [ClientSnapshot] of the last refresh changing the state
+    ///
+    /// The snapshot is never modified, a new one being swapped in by each refresh changing the state
+    pub fn snapshot(&self) -> Arc<ClientSnapshot> {
+        Arc::clone(&self.state.snapshot.read().unwrap())
+    }
+
+    pub fn local_receiver(&self) -> bool {
+        self.local_receiver
+    }
+}
+
+/// The state shared between a [Client] and its receiving threads
+#[derive(Clone)]
+struct ClientState {
+    instant: Instant,
+    frame_trackers: Arc<Mutex<HashMap<FrameKey, FrameTracker>>>,
+    source_list: Arc<RwLock<IndexMap<String, SourceCollection>>>,
+    snapshot: Arc<RwLock<Arc<ClientSnapshot>>>,
+    listeners: Arc<RwLock<Vec<Arc<dyn TuioListener>>>>,
+    event_senders: Arc<Mutex<Vec<Sender<TuioEvent>>>>,
+    /// The decoded [Protocol], or [None] to detect it per bundle
+    protocol: Option<Protocol>,
+    /// The inactivity duration after which a source is removed, or [None] to keep sources until they leave
+    source_timeout: Option<Duration>,
+    /// The last errors of the processed packets, up to [MAX_KEPT_ERRORS]
+    errors: Arc<Mutex<Vec<TuioError>>>,
+}
+
+/// The number of packet errors kept until [Client::take_errors] is called
+const MAX_KEPT_ERRORS: usize = 128;
+
+impl ClientState {
+    /// Processes OSC packets in order, swapping a new [ClientSnapshot] if the state changed
+    ///
+    /// The packets dropped by the [PacketBuffer] before these ones are reported first. A packet failing to be processed
+    /// does not stop the processing of the next ones, its [TuioError] being kept for [Client::take_errors] and passed
+    /// to [TuioListener::packet_error] along with the `errors` of the packets which failed to be decoded
+    /// The [TuioListener]s are notified of the events of each frame once the state is unlocked
+    fn process_osc_packets(&self, packets: impl IntoIterator<Item = OscPacket>, dropped_packets: usize, mut errors: Vec<TuioError>) -> Option<TuioEvents> {
+        let mut source_list = self.source_list.write().unwrap();
+        let mut frames = Vec::new();
+
+        if dropped_packets > 0 {
+            frames.push((TuioEvents { dropped_packets, ..Default::default() }, self.instant.elapsed()));
+        }
+
+        for packet in packets {
+            let mut frame_events = TuioEvents::default();
+
+            match self.process_osc_packet(&mut source_list, packet, &mut frame_events) {
+                Ok(true) => frames.push((frame_events, self.instant.elapsed())),
+                Ok(false) => {},
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if let Some(timeout) = self.source_timeout {
+            let mut frame_events = TuioEvents::default();
+            self.remove_silent_sources(&mut source_list, timeout, &mut frame_events);
+
+            if !frame_events.source_events.is_empty() {
+                frames.push((frame_events, self.instant.elapsed()));
+            }
+        }
+
+        if !frames.is_empty() {
+            *self.snapshot.write().unwrap() = Arc::new(ClientSnapshot {source_list: source_list.clone()});
         }
+
+        drop(source_list);
+
+        // Listeners can add or remove listeners from their callbacks
+        let listeners = self.listeners.read().unwrap().clone();
+
+        if !errors.is_empty() {
+            for listener in &listeners {
+                for err in &errors {
+                    listener.packet_error(err);
+                }
+            }
+
+            let mut kept_errors = self.errors.lock().unwrap();
+            kept_errors.extend(errors);
+            let excess = kept_errors.len().saturating_sub(MAX_KEPT_ERRORS);
+            kept_errors.drain(..excess);
+        }
+
+        if frames.is_empty() {
+            return None;
+        }
+
+        // Listeners are notified before locking the event senders, as they can call Client::events
+        for (frame_events, frame_time) in &frames {
+            for listener in &listeners {
+                frame_events.notify(listener.as_ref());
+                listener.refresh(*frame_time);
+            }
+        }
+
+        let mut events = TuioEvents::default();
+        let mut channel_events = Vec::new();
+
+        for (frame_events, _) in frames {
+            channel_events.extend(frame_events.clone());
+            events.append(frame_events);
+        }
+
+        // Drops the senders whose receiver has been dropped, the lock only covering the sends
+        self.event_senders.lock().unwrap().retain(|sender| channel_events.iter().all(|event| sender.send(event.clone()).is_ok()));
+
+        Some(events)
     }
 
-    /// Update frame parameters based on a frame number
-    /// 
+    /// Update the frame parameters of a source's profile based on a frame number
+    ///
+    /// A frame older than the last one is late and discarded, unless the sequence went back by more than 100 frames
+    /// or the last frame was accepted more than [LATE_FRAME_TIMEOUT] ago, which happens when the source restarted.
+    /// Negative frames, such as the end-of-session bundles of departing sources, are never late.
     /// Returns true if the frame is a new frame
-    /// # Argument
+    /// # Arguments
+    /// * `key` - the source name and profile the frame belongs to
     /// * `frame` - the new frame number
-    fn update_frame(&self, frame: i32) -> bool {
-        if frame >= 0 {
-            let current_frame = self.current_frame.get();
-            
-            if frame > current_frame {
-                self.current_time.set(self.instant.elapsed());
-            }
-            
-            if frame >= current_frame || current_frame - frame > 100 {
-                self.current_frame.set(frame);
-                return true;
+    fn update_frame(&self, key: FrameKey, frame: i32) -> bool {
+        if frame < 0 {
+            return true;
+        }
+
+        let mut frame_trackers = self.frame_trackers.lock().unwrap();
+        let tracker = frame_trackers.entry(key).or_insert(FrameTracker {frame: -1, ..Default::default()});
+        let time = self.instant.elapsed();
+
+        if frame >= tracker.frame || tracker.frame - frame > 100 || time.saturating_sub(tracker.time) > LATE_FRAME_TIMEOUT {
+            tracker.frame = frame;
+            tracker.time = time;
+            return true;
+        }
+        false
+    }
+
+    /// Returns the [SourceCollection] of a source, pushing a [SourceEvent::New] if the source is new
+    ///
+    /// # Arguments
+    /// * `source_list` - the locked source list
+    /// * `source_name` - the source of the bundle
+    /// * `protocol` - the [Protocol] of the bundle
+    /// * `frame` - the frame of the bundle
+    /// * `events` - the events of the frame
+    fn update_source<'a>(&self, source_list: &'a mut IndexMap<String, SourceCollection>, source_name: &str, protocol: Protocol, frame: i32, events: &mut TuioEvents) -> &'a mut SourceCollection {
+        let time = self.instant.elapsed();
+
+        let source_collection = source_list.entry(source_name.to_string()).or_insert_with(|| {
+            let info = SourceInfo::new(source_name, protocol, time);
+            events.source_events.push(SourceEvent::New(SourceData { source_name: source_name.into(), info: info.clone() }));
+            SourceCollection { info, ..Default::default() }
+        });
+
+        source_collection.info.update(protocol, frame, time);
+        source_collection
+    }
+
+    /// Removes the sources whose last bundle is older than the timeout, with their entities
+    ///
+    /// # Arguments
+    /// * `source_list` - the locked source list
+    /// * `timeout` - the inactivity duration after which a source is removed
+    /// * `events` - the events of the frame
+    fn remove_silent_sources(&self, source_list: &mut IndexMap<String, SourceCollection>, timeout: Duration, events: &mut TuioEvents) {
+        let time = self.instant.elapsed();
+        let silent_sources: Vec<String> = source_list.iter()
+            .filter(|(_, source_collection)| time.saturating_sub(source_collection.info.last_seen) > timeout)
+            .map(|(source_name, _)| source_name.clone())
+            .collect();
+
+        if silent_sources.is_empty() {
+            return;
+        }
+
+        self.frame_trackers.lock().unwrap().retain(|(source_name, _), _| !silent_sources.contains(source_name));
+
+        for source_name in silent_sources {
+            if let Some(source_collection) = source_list.shift_remove(&source_name) {
+                clear_source(source_collection, &source_name, events);
             }
-            else if self.instant.elapsed() - self.current_time.get() > Duration::from_millis(100){
-                self.current_time.set(self.instant.elapsed());
-                return false;
+        }
+    }
+
+    /// Ends a source's profile after its end-of-session bundle, removing the source once it ended every profile it sent
+    ///
+    /// # Arguments
+    /// * `source_list` - the locked source list
+    /// * `key` - the source name and ended profile
+    /// * `events` - the events of the frame
+    fn end_profile(&self, source_list: &mut IndexMap<String, SourceCollection>, key: &FrameKey, events: &mut TuioEvents) {
+        let mut frame_trackers = self.frame_trackers.lock().unwrap();
+        frame_trackers.remove(key);
+
+        if !frame_trackers.keys().any(|(source_name, _)| *source_name == key.0) {
+            if let Some(source_collection) = source_list.shift_remove(&key.0) {
+                clear_source(source_collection, &key.0, events);
             }
         }
-        false
     }
 
-    fn process_osc_packet(&self, packet: OscPacket, events: &mut TuioEvents) -> Result<bool, TuioError> {
+    fn process_osc_packet(&self, source_list: &mut IndexMap<String, SourceCollection>, packet: OscPacket, events: &mut TuioEvents) -> Result<bool, TuioError> {
         if let OscPacket::Bundle(bundle) = packet {
-            let decoded_bundle = OscDecoder::decode_bundle(bundle)?;
-            
-            let to_keep: HashSet<i32> = HashSet::from_iter(decoded_bundle.alive);
-            
-            if self.update_frame(decoded_bundle.fseq) {
-                let mut source_list = self.source_list.borrow_mut();
-                let source_collection = source_list.entry(decoded_bundle.source.clone()).or_default(); 
-                match decoded_bundle.tuio_type {
-                    osc_encode_decode::TuioBundleType::Cursor => {
-                        let cursor_map = &mut source_collection.cursor_map;
-
-                        for cursor in retain_by_ids(cursor_map, to_keep).into_iter() {
-                            events.cursor_events.push(CursorEvent::Remove(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                        }
+            match self.protocol.or_else(|| Protocol::detect(&bundle)) {
+                Some(Protocol::Tuio2) => self.process_tuio2_bundle(source_list, bundle, events),
+                _ => self.process_tuio_bundle(source_list, bundle, events),
+            }
+        }
+        else {
+            Err(TuioError::NotABundle(packet))
+        }
+    }
 
-                        if let Some(Set::Cursor(cursor_collection)) = decoded_bundle.set {
-                            for cursor in cursor_collection {
-                                match cursor_map.entry(cursor.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.cursor_events.push(CursorEvent::Update(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                                        entry.insert(cursor);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.cursor_events.push(CursorEvent::New(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                                        entry.insert(cursor);
-                                    },
-                                }
-                            }
-                        }
-                    },
-                    osc_encode_decode::TuioBundleType::Object => {
-                        let object_map = &mut source_collection.object_map;
+    fn process_tuio_bundle(&self, source_list: &mut IndexMap<String, SourceCollection>, bundle: OscBundle, events: &mut TuioEvents) -> Result<bool, TuioError> {
+        let decoded_bundle = OscDecoder::decode_bundle(bundle)?;
+        let key = (decoded_bundle.source.clone(), Some(decoded_bundle.tuio_type.clone()));
 
-                        for object in retain_by_ids(object_map, to_keep).into_iter() {
-                            events.object_events.push(ObjectEvent::Remove(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                        }
+        // A departing source sends its profiles with an empty alive list and the frame -1, which sources without frame
+        // numbers also send while idle, so that it only ends the profiles which sent frames
+        let empty_frame = decoded_bundle.fseq < 0 && decoded_bundle.alive.is_empty();
 
-                        if let Some(Set::Object(object_collection)) = decoded_bundle.set {
-                            for object in object_collection {
-                                match object_map.entry(object.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.object_events.push(ObjectEvent::Update(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                                        entry.insert(object);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.object_events.push(ObjectEvent::New(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                                        entry.insert(object);
-                                    },
-                                }
-                            }
+        if empty_frame && !source_list.contains_key(&key.0) {
+            return Ok(false);
+        }
+
+        let end_of_session = empty_frame && self.frame_trackers.lock().unwrap().contains_key(&key);
+
+        let to_keep: HashSet<i32> = HashSet::from_iter(decoded_bundle.alive);
+
+        if self.update_frame(key.clone(), decoded_bundle.fseq) {
+            let source_name = decoded_bundle.source;
+            let source_collection = self.update_source(source_list, &source_name, Protocol::Tuio1, decoded_bundle.fseq, events);
+
+            match decoded_bundle.tuio_type {
+                osc_encode_decode::TuioBundleType::Cursor => {
+                    let set = if let Some(Set::Cursor(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    if self.protocol.is_none() {
+                        apply_pointers(source_collection, &to_keep, set.into_iter().map(Pointer::from).collect(), &source_name, Protocol::Tuio1, events);
+                    }
+                    else {
+                        for change in apply_set(&mut source_collection.cursor_map, &to_keep, set, Cursor::get_session_id) {
+                            events.cursor_events.push(match change {
+                                EntityChange::New(cursor) => CursorEvent::New(CursorData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                                EntityChange::Update(cursor) => CursorEvent::Update(CursorData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                                EntityChange::Remove(cursor) => CursorEvent::Remove(CursorData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                            });
                         }
-                    },
-                    osc_encode_decode::TuioBundleType::Blob => {
-                        let blob_map = &mut source_collection.blob_map;
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Object => {
+                    let set = if let Some(Set::Object(set)) = decoded_bundle.set {set} else {Vec::new()};
 
-                        for blob in retain_by_ids(blob_map, to_keep).into_iter() {
-                            events.blob_events.push(BlobEvent::Remove(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
+                    if self.protocol.is_none() {
+                        apply_tokens(source_collection, &to_keep, set.into_iter().map(Token::from).collect(), &source_name, Protocol::Tuio1, events);
+                    }
+                    else {
+                        for change in apply_set(&mut source_collection.object_map, &to_keep, set, Object::get_session_id) {
+                            events.object_events.push(match change {
+                                EntityChange::New(object) => ObjectEvent::New(ObjectData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                                EntityChange::Update(object) => ObjectEvent::Update(ObjectData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                                EntityChange::Remove(object) => ObjectEvent::Remove(ObjectData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                            });
                         }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Blob => {
+                    let set = if let Some(Set::Blob(set)) = decoded_bundle.set {set} else {Vec::new()};
 
-                        if let Some(Set::Blob(blob_collection)) = decoded_bundle.set {
-                            for blob in blob_collection {
-                                match blob_map.entry(blob.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.blob_events.push(BlobEvent::Update(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
-                                        entry.insert(blob);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.blob_events.push(BlobEvent::New(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
-                                        entry.insert(blob);
-                                    },
-                                }
-                            }
+                    if self.protocol.is_none() {
+                        apply_bounds(source_collection, &to_keep, set.into_iter().map(Bounds::from).collect(), &source_name, Protocol::Tuio1, events);
+                    }
+                    else {
+                        for change in apply_set(&mut source_collection.blob_map, &to_keep, set, Blob::get_session_id) {
+                            events.blob_events.push(match change {
+                                EntityChange::New(blob) => BlobEvent::New(BlobData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                                EntityChange::Update(blob) => BlobEvent::Update(BlobData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                                EntityChange::Remove(blob) => BlobEvent::Remove(BlobData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                            });
                         }
-                    },
-                    osc_encode_decode::TuioBundleType::Unknown => (),
-                }
-                Ok(true)
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Cursor25D => {
+                    let set = if let Some(Set::Cursor25D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.cursor_25d_map, &to_keep, set, Cursor25D::get_session_id) {
+                        events.cursor_25d_events.push(match change {
+                            EntityChange::New(cursor) => Cursor25DEvent::New(Cursor25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                            EntityChange::Update(cursor) => Cursor25DEvent::Update(Cursor25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                            EntityChange::Remove(cursor) => Cursor25DEvent::Remove(Cursor25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Object25D => {
+                    let set = if let Some(Set::Object25D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.object_25d_map, &to_keep, set, Object25D::get_session_id) {
+                        events.object_25d_events.push(match change {
+                            EntityChange::New(object) => Object25DEvent::New(Object25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                            EntityChange::Update(object) => Object25DEvent::Update(Object25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                            EntityChange::Remove(object) => Object25DEvent::Remove(Object25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Blob25D => {
+                    let set = if let Some(Set::Blob25D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.blob_25d_map, &to_keep, set, Blob25D::get_session_id) {
+                        events.blob_25d_events.push(match change {
+                            EntityChange::New(blob) => Blob25DEvent::New(Blob25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                            EntityChange::Update(blob) => Blob25DEvent::Update(Blob25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                            EntityChange::Remove(blob) => Blob25DEvent::Remove(Blob25DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Cursor3D => {
+                    let set = if let Some(Set::Cursor3D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.cursor_3d_map, &to_keep, set, Cursor3D::get_session_id) {
+                        events.cursor_3d_events.push(match change {
+                            EntityChange::New(cursor) => Cursor3DEvent::New(Cursor3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                            EntityChange::Update(cursor) => Cursor3DEvent::Update(Cursor3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                            EntityChange::Remove(cursor) => Cursor3DEvent::Remove(Cursor3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, cursor }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Object3D => {
+                    let set = if let Some(Set::Object3D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.object_3d_map, &to_keep, set, Object3D::get_session_id) {
+                        events.object_3d_events.push(match change {
+                            EntityChange::New(object) => Object3DEvent::New(Object3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                            EntityChange::Update(object) => Object3DEvent::Update(Object3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                            EntityChange::Remove(object) => Object3DEvent::Remove(Object3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, object }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Blob3D => {
+                    let set = if let Some(Set::Blob3D(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(&mut source_collection.blob_3d_map, &to_keep, set, Blob3D::get_session_id) {
+                        events.blob_3d_events.push(match change {
+                            EntityChange::New(blob) => Blob3DEvent::New(Blob3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                            EntityChange::Update(blob) => Blob3DEvent::Update(Blob3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                            EntityChange::Remove(blob) => Blob3DEvent::Remove(Blob3DData { source_name: source_name.clone(), protocol: Protocol::Tuio1, blob }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Custom(format) => {
+                    let set = if let Some(Set::Custom(set)) = decoded_bundle.set {set} else {Vec::new()};
+
+                    for change in apply_set(source_collection.custom_map.entry(format).or_default(), &to_keep, set, CustomProfile::get_session_id) {
+                        events.custom_events.push(match change {
+                            EntityChange::New(custom) => CustomEvent::New(CustomData { source_name: source_name.clone(), protocol: Protocol::Tuio1, custom }),
+                            EntityChange::Update(custom) => CustomEvent::Update(CustomData { source_name: source_name.clone(), protocol: Protocol::Tuio1, custom }),
+                            EntityChange::Remove(custom) => CustomEvent::Remove(CustomData { source_name: source_name.clone(), protocol: Protocol::Tuio1, custom }),
+                        });
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Unknown => (),
             }
-            else {
-                Ok(false)
+
+            if end_of_session {
+                self.end_profile(source_list, &key, events);
             }
+            Ok(true)
         }
         else {
-            Err(TuioError::NotABundle(packet))
+            Ok(false)
         }
     }
 
-    pub fn local_receiver(&self) -> bool {
-        self.local_receiver
+    fn process_tuio2_bundle(&self, source_list: &mut IndexMap<String, SourceCollection>, bundle: OscBundle, events: &mut TuioEvents) -> Result<bool, TuioError> {
+        let frame_bundle = Tuio2Decoder::decode_bundle(bundle)?;
+
+        let key = (frame_bundle.frame.source.clone(), None);
+        let empty_frame = frame_bundle.frame.frame_id < 0 && frame_bundle.alive.is_empty();
+
+        if empty_frame && !source_list.contains_key(&key.0) {
+            return Ok(false);
+        }
+
+        // As for TUIO 1.1 bundles, only the sources which sent frames end their session
+        let end_of_session = empty_frame && self.frame_trackers.lock().unwrap().contains_key(&key);
+
+        if !self.update_frame(key.clone(), frame_bundle.frame.frame_id) {
+            return Ok(false);
+        }
+
+        let to_keep: HashSet<i32> = HashSet::from_iter(frame_bundle.alive);
+        let source_name = frame_bundle.frame.source;
+        let source_collection = self.update_source(source_list, &source_name, Protocol::Tuio2, frame_bundle.frame.frame_id, events);
+
+        apply_tokens(source_collection, &to_keep, frame_bundle.tokens, &source_name, Protocol::Tuio2, events);
+        apply_pointers(source_collection, &to_keep, frame_bundle.pointers, &source_name, Protocol::Tuio2, events);
+        apply_bounds(source_collection, &to_keep, frame_bundle.bounds, &source_name, Protocol::Tuio2, events);
+
+        for change in apply_set(&mut source_collection.symbol_map, &to_keep, frame_bundle.symbols, Symbol::get_session_id) {
+            events.symbol_events.push(match change {
+                EntityChange::New(symbol) => SymbolEvent::New(SymbolData { source_name: source_name.clone(), protocol: Protocol::Tuio2, symbol }),
+                EntityChange::Update(symbol) => SymbolEvent::Update(SymbolData { source_name: source_name.clone(), protocol: Protocol::Tuio2, symbol }),
+                EntityChange::Remove(symbol) => SymbolEvent::Remove(SymbolData { source_name: source_name.clone(), protocol: Protocol::Tuio2, symbol }),
+            });
+        }
+
+        for geometry in retain_by_ids(&mut source_collection.geometry_map, &to_keep) {
+            events.geometry_events.push(GeometryEvent::Remove(GeometryData { source_name: source_name.clone(), protocol: Protocol::Tuio2, geometry }));
+        }
+
+        for geometry in frame_bundle.geometries.into_iter().filter(|geometry| to_keep.contains(&geometry.get_session_id())) {
+            events.geometry_events.push(match source_collection.geometry_map.entry(geometry.get_session_id()) {
+                indexmap::map::Entry::Occupied(mut entry) => {
+                    entry.get_mut().merge(geometry);
+                    GeometryEvent::Update(GeometryData { source_name: source_name.clone(), protocol: Protocol::Tuio2, geometry: entry.get().clone() })
+                },
+                indexmap::map::Entry::Vacant(entry) => {
+                    GeometryEvent::New(GeometryData { source_name: source_name.clone(), protocol: Protocol::Tuio2, geometry: entry.insert(geometry).clone() })
+                },
+            });
+        }
+
+        let association_graph = &mut source_collection.association_graph;
+        let mut removed = association_graph.retain(&to_keep);
+
+        if let Some(alive_associations) = frame_bundle.alive_associations {
+            removed.extend(association_graph.retain(&HashSet::from_iter(alive_associations)));
+        }
+
+        for association in removed {
+            events.association_events.push(AssociationEvent::Remove(AssociationData { source_name: source_name.clone(), protocol: Protocol::Tuio2, association }));
+        }
+
+        for association in frame_bundle.associations.into_iter().filter(|association| to_keep.contains(&association.get_session_id())) {
+            match association_graph.insert(association.clone()) {
+                None => events.association_events.push(AssociationEvent::New(AssociationData { source_name: source_name.clone(), protocol: Protocol::Tuio2, association })),
+                Some(previous) if previous != association => events.association_events.push(AssociationEvent::Update(AssociationData { source_name: source_name.clone(), protocol: Protocol::Tuio2, association })),
+                Some(_) => {},
+            }
+        }
+
+        if end_of_session {
+            self.end_profile(source_list, &key, events);
+        }
+
+        Ok(true)
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// An async TUIO client receiving in tokio tasks, polled as a [Stream] of [TuioEvents]
+///
+/// Each received packet changing the state yields its own [TuioEvents]
+#[cfg(feature = "async")]
+pub struct AsyncClient {
+    client: Client,
+    osc_receivers: Vec<Arc<AsyncRoscReceiver>>,
+    packet_receiver: Option<UnboundedReceiver<Result<OscPacket, TuioError>>>,
+    tasks: Vec<task::JoinHandle<()>>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient {
+    /// Creates an [AsyncClient] with an [AsyncUdpReceiver] binded to the provided port on localhost
+    ///
+    /// Must be called from within a tokio runtime
+    /// # Arguments
+    /// * `port` - the listening port
+    pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
+        Ok(Self::from_async_osc_receiver(AsyncUdpReceiver::from_port(port)?))
     }
-}
\ No newline at end of file
+
+    /// Creates an [AsyncClient] from an async OSC receiver implementing [AsyncOscReceiver] trait
+    ///
+    /// # Arguments
+    /// * `osc_receiver` - a receiver implementing [AsyncOscReceiver]
+    pub fn from_async_osc_receiver(osc_receiver: impl AsyncOscReceiver<OscPacket, OscReceiverError> + Send + Sync + 'static) -> Self {
+        Self {
+            client: Client::from_receiver_list(Vec::new()),
+            osc_receivers: vec![Arc::new(osc_receiver)],
+            packet_receiver: None,
+            tasks: Vec::new(),
+        }
+    }
+
+    /// Adds an async OSC receiver implementing [AsyncOscReceiver] trait
+    ///
+    /// # Arguments
+    /// * `osc_receiver` - a receiver implementing [AsyncOscReceiver]
+    pub fn add_async_osc_receiver(&mut self, osc_receiver: impl AsyncOscReceiver<OscPacket, OscReceiverError> + Send + Sync + 'static) {
+        self.osc_receivers.push(Arc::new(osc_receiver));
+    }
+
+    /// Returns this [AsyncClient] decoding the given [Protocol]
+    ///
+    /// # Arguments
+    /// * `protocol` - the TUIO [Protocol] version to decode
+    pub fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.client.state.protocol = Some(protocol);
+        self
+    }
+
+    /// Returns this [AsyncClient] removing the sources which sent no bundle for a duration, as [Client::with_source_timeout]
+    ///
+    /// # Arguments
+    /// * `timeout` - the inactivity duration after which a source is removed
+    pub fn with_source_timeout(mut self, timeout: Duration) -> Self {
+        self.client.state.source_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns this [AsyncClient] accepting both TUIO 1.1 and TUIO 2.0, as [Client::with_dual_stack]
+    pub fn with_dual_stack(mut self) -> Self {
+        self.client.state.protocol = None;
+        self
+    }
+
+    /// Returns the [Client] holding the state, updated as the stream is polled
+    pub fn get_client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Connects the receivers, spawning one tokio task per receiver
+    pub async fn connect(&mut self) -> Result<(), OscReceiverError> {
+        if self.packet_receiver.is_some() {
+            return Err(OscReceiverError::AlreadyConnected());
+        }
+
+        let (packet_sender, packet_receiver) = mpsc::unbounded_channel();
+
+        for receiver in &self.osc_receivers {
+            if let Err(err) = receiver.connect().await {
+                self.disconnect();
+                return Err(OscReceiverError::Connect(err));
+            }
+
+            let receiver = Arc::clone(receiver);
+            let packet_sender = packet_sender.clone();
+
+            self.tasks.push(tokio::spawn(async move {
+                loop {
+                    let received = match receiver.recv().await {
+                        Ok(packet) => Ok(packet),
+                        // A malformed packet should not stop the reception, its error being reported as on refresh
+                        Err(OscReceiverError::Decode(err)) => Err(TuioError::Decode(err)),
+                        // The stream ends once every receiver stopped
+                        Err(_) => break,
+                    };
+
+                    if packet_sender.send(received).is_err() {
+                        break;
+                    }
+                }
+            }));
+        }
+
+        self.packet_receiver = Some(packet_receiver);
+        Ok(())
+    }
+
+    /// Disconnects the receivers and stops their tasks, ending the stream
+    pub fn disconnect(&mut self) {
+        for task in self.tasks.drain(..) {
+            task.abort();
+        }
+
+        for receiver in &self.osc_receivers {
+            receiver.disconnect();
+        }
+
+        self.packet_receiver = None;
+    }
+
+    /// Waits for the next [TuioEvents], returning [None] once disconnected
+    pub async fn next_events(&mut self) -> Option<TuioEvents> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl Stream for AsyncClient {
+    type Item = TuioEvents;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let Some(packet_receiver) = this.packet_receiver.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        loop {
+            let Some(received) = ready!(packet_receiver.poll_recv(cx)) else {
+                this.packet_receiver = None;
+                return Poll::Ready(None);
+            };
+
+            // Packet errors are kept by the client, as on refresh
+            let events = match received {
+                Ok(packet) => this.client.state.process_osc_packets([packet], 0, Vec::new()),
+                Err(err) => this.client.state.process_osc_packets([], 0, vec![err]),
+            };
+
+            if let Some(events) = events {
+                return Poll::Ready(Some(events));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for AsyncClient {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosc::{OscMessage, OscPacket, OscType};
+
+    use std::{net::{IpAddr, Ipv4Addr}, sync::{OnceLock, Weak}};
+
+    use crate::{cursor::{Position, Position3D}, framing::Framing, osc_encode_decode::{OscEncoder, EncodeOsc}, osc_receiver::{ChannelReceiver, TcpReceiver}, server::{Server, TcpSender}, tuio2::{Frame, FrameBundle, Token, Tuio2Encoder, EncodeTuio2}};
+
+    use super::*;
+
+    #[test]
+    fn tuio2_refresh() {
+        let client = Client::from_port(0).unwrap().with_protocol(Protocol::Tuio2);
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.tokens = vec![Token::new(0, 4, Position {x: 0.1, y: 0.2}, 0.)];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.token_events[..], [TokenEvent::New(TokenData {ref source_name, protocol: Protocol::Tuio2, ref token})] if source_name == "test" && token.get_component_id() == 4));
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(2, 640, 480, "test"));
+        frame_bundle.alive = vec![];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.token_events[..], [TokenEvent::Remove(_)]));
+        assert!(client.source_list.read().unwrap()["test"].token_map.is_empty());
+    }
+
+    #[test]
+    fn dual_stack_refresh() {
+        let client = Client::from_port(0).unwrap().with_dual_stack();
+
+        let cursor_bundle = OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "tuio1".into(), 1);
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(cursor_bundle));
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(2, 640, 480, "tuio2"));
+        frame_bundle.pointers = vec![Pointer::new(0, 0, Position {x: 0.3, y: 0.4}, 0., 0., 0.1, 1.)];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(events.cursor_events.is_empty());
+        assert!(matches!(events.pointer_events[..], [
+            PointerEvent::New(PointerData {protocol: Protocol::Tuio1, ref pointer, ..}),
+            PointerEvent::New(PointerData {protocol: Protocol::Tuio2, ..})
+        ] if pointer.get_x_position() == 0.1));
+        assert_eq!(client.source_list.read().unwrap()["tuio1"].pointer_map.len(), 1);
+    }
+
+    #[test]
+    fn dual_stack_other_profiles() {
+        let client = Client::from_port(0).unwrap().with_dual_stack();
+
+        let cursor_bundle = OscEncoder::encode_cursor_25d_bundle(&[Cursor25D::new(0, Position3D {x: 0.1, y: 0.2, z: 0.3})], "tuio1".into(), 1);
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(cursor_bundle));
+
+        let events = client.refresh().unwrap();
+        assert!(events.pointer_events.is_empty());
+        assert!(matches!(events.cursor_25d_events[..], [Cursor25DEvent::New(Cursor25DData {protocol: Protocol::Tuio1, ..})]));
+
+        let source_list = client.source_list.read().unwrap();
+        assert_eq!(source_list["tuio1"].cursor_25d_map.len(), 1);
+        assert!(source_list["tuio1"].pointer_map.is_empty());
+    }
+
+    /// Returns the cursor and object bundles sent by a source for each frame, both profiles sharing the frame number
+    fn source_frames(source_name: &str, frames: impl IntoIterator<Item = i32>) -> Vec<Vec<OscPacket>> {
+        frames.into_iter().map(|frame| {
+            let position = Position {x: frame as f32 / 1000., y: 0.};
+
+            vec![
+                OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, position.clone())], source_name.into(), frame)),
+                OscPacket::Bundle(OscEncoder::encode_object_bundle(&[Object::new(1, 2, position.clone(), 0.)], source_name.into(), frame)),
+            ]
+        }).collect()
+    }
+
+    /// Pushes the frames of every source into the client's buffer, one frame of each source after the other
+    fn push_interleaved(client: &Client, sources: Vec<Vec<Vec<OscPacket>>>) {
+        let mut sources: Vec<_> = sources.into_iter().map(Vec::into_iter).collect();
+        let mut buffer = client.packet_buffer.lock().unwrap();
+
+        while sources.iter().any(|frames| frames.len() > 0) {
+            for frame in sources.iter_mut().filter_map(Iterator::next) {
+                for packet in frame {
+                    buffer.push(packet);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn interleaved_sources_refresh() {
+        let client = Client::from_port(0).unwrap();
+
+        push_interleaved(&client, vec![
+            source_frames("first", 500..505),
+            source_frames("second", 1..5),
+            source_frames("third", 40..46),
+        ]);
+
+        let events = client.refresh().unwrap();
+        assert_eq!(events.cursor_events.len(), 15);
+        assert_eq!(events.object_events.len(), 15);
+
+        let source_list = client.source_list.read().unwrap();
+
+        for (source_name, last_frame) in [("first", 504), ("second", 4), ("third", 45)] {
+            assert_eq!(source_list[source_name].cursor_map[&0].get_x_position(), last_frame as f32 / 1000.);
+            assert_eq!(source_list[source_name].object_map[&1].get_x_position(), last_frame as f32 / 1000.);
+        }
+    }
+
+    #[test]
+    fn interleaved_late_frames() {
+        let client = Client::from_port(0).unwrap();
+
+        push_interleaved(&client, vec![
+            source_frames("first", [10, 8, 11]),
+            source_frames("second", [1, 2]),
+            // Going back by more than 100 frames is a restart
+            source_frames("third", [300, 100]),
+        ]);
+
+        let events = client.refresh().unwrap();
+        assert_eq!(events.cursor_events.len(), 6);
+
+        let source_list = client.source_list.read().unwrap();
+        assert!(events.cursor_events.iter().all(|event| !matches!(event, CursorEvent::Update(CursorData {ref cursor, ..}) if cursor.get_x_position() == 0.008)));
+        assert_eq!(source_list["first"].cursor_map[&0].get_x_position(), 0.011);
+        assert_eq!(source_list["second"].object_map[&1].get_x_position(), 0.002);
+        assert_eq!(source_list["third"].cursor_map[&0].get_x_position(), 0.1);
+    }
+
+    #[test]
+    fn late_frame_timeout() {
+        let client = Client::from_port(0).unwrap();
+
+        push_interleaved(&client, vec![source_frames("test", [10, 8])]);
+        client.refresh();
+        assert_eq!(client.source_list.read().unwrap()["test"].cursor_map[&0].get_x_position(), 0.01);
+
+        // An older frame is accepted once the last one is too old
+        thread::sleep(LATE_FRAME_TIMEOUT * 2);
+        push_interleaved(&client, vec![source_frames("test", [9])]);
+        client.refresh();
+        assert_eq!(client.source_list.read().unwrap()["test"].cursor_map[&0].get_x_position(), 0.009);
+    }
+
+    #[test]
+    fn frameless_source() {
+        let client = Client::from_port(0).unwrap();
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "test".into(), -1)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.source_events[..], [SourceEvent::New(_)]));
+
+        // Empty bundles without frame numbers only remove the entities, never the source
+        for _ in 0..3 {
+            client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "test".into(), -1)));
+        }
+
+        let events = client.refresh().unwrap();
+        assert!(events.source_events.is_empty());
+        assert!(matches!(events.cursor_events[..], [CursorEvent::Remove(_)]));
+        assert!(client.source_list.read().unwrap()["test"].cursor_map.is_empty());
+    }
+
+    #[test]
+    fn buffer_overflow() {
+        let client = Client::from_port(0).unwrap().with_buffer_policy(BufferPolicy::DropOldest(2));
+        push_interleaved(&client, vec![source_frames("test", 1..3)]);
+
+        let events = client.refresh().unwrap();
+        assert_eq!(events.dropped_packets, 2);
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(_)]));
+        assert!(matches!(events.into_iter().last(), Some(TuioEvent::PacketsDropped(2))));
+
+        push_interleaved(&client, vec![source_frames("test", 3..4)]);
+        assert_eq!(client.refresh().unwrap().dropped_packets, 0);
+        assert_eq!(client.get_dropped_packets(), 2);
+
+        let client = Client::from_port(0).unwrap().with_buffer_policy(BufferPolicy::Unbounded);
+        push_interleaved(&client, vec![source_frames("test", 1..101)]);
+        assert_eq!(client.refresh().unwrap().cursor_events.len(), 100);
+        assert_eq!(client.get_dropped_packets(), 0);
+    }
+
+    #[test]
+    fn coalesced_buffer() {
+        let client = Client::from_port(0).unwrap().with_buffer_policy(BufferPolicy::Coalesce(6));
+
+        push_interleaved(&client, vec![
+            source_frames("first", 1..6),
+            source_frames("second", 1..2),
+        ]);
+
+        // Once full, the older cursor and object bundles of each source are dropped
+        let events = client.refresh().unwrap();
+        assert_eq!(events.dropped_packets, 6);
+        assert_eq!(client.packet_buffer.lock().unwrap().packets.len(), 0);
+
+        let source_list = client.source_list.read().unwrap();
+        assert_eq!(source_list["first"].cursor_map[&0].get_x_position(), 0.005);
+        assert_eq!(source_list["first"].object_map[&1].get_x_position(), 0.005);
+        assert_eq!(source_list["second"].cursor_map[&0].get_x_position(), 0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be at least 1")]
+    fn empty_buffer_policy() {
+        let _ = Client::from_port(0).unwrap().with_buffer_policy(BufferPolicy::Backpressure(0));
+    }
+
+    #[test]
+    fn coalesced_tuio2_frames() {
+        let client = Client::from_port(0).unwrap().with_dual_stack().with_buffer_policy(BufferPolicy::Coalesce(4));
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "tuio2"));
+        frame_bundle.tokens = vec![Token::new(0, 4, Position {x: 0.1, y: 0.2}, 0.)];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        for frame in 1..3 {
+            client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "tuio1".into(), frame)));
+        }
+
+        // The token is only created by the first frame, which must be kept
+        let mut frame_bundle = FrameBundle::new(Frame::new(2, 640, 480, "tuio2"));
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "tuio1".into(), 3)));
+
+        let events = client.refresh().unwrap();
+        assert_eq!(events.dropped_packets, 1);
+        assert_eq!(client.source_list.read().unwrap()["tuio2"].token_map.len(), 1);
+    }
+
+    #[test]
+    fn backpressure_buffer() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        server.set_source_name("test");
+        let client = Client::from_osc_receiver(receiver).with_buffer_policy(BufferPolicy::Backpressure(1));
+        client.connect().unwrap();
+
+        server.init_frame();
+        let cursor_id = server.create_cursor(0., 0.);
+        server.commit_frame().unwrap();
+
+        for x in 1..=10 {
+            server.init_frame();
+            server.update_cursor(cursor_id, x as f32 / 10., 0.);
+            server.commit_frame().unwrap();
+        }
+
+        let start = Instant::now();
+
+        while client.source_list.read().unwrap().get("test@local").and_then(|source| source.cursor_map.get(&cursor_id)).map(Cursor::get_x_position) != Some(1.) {
+            assert!(start.elapsed() < Duration::from_secs(1), "last update not received");
+            assert!(client.packet_buffer.lock().unwrap().packets.len() <= 1);
+            client.wait_for_events(Duration::from_millis(10));
+        }
+
+        assert_eq!(client.get_dropped_packets(), 0);
+        client.disconnect();
+    }
+
+    #[test]
+    fn tuio2_association_refresh() {
+        let client = Client::from_port(0).unwrap().with_protocol(Protocol::Tuio2);
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.associations = vec![Association::container(0, 0, vec![1, 2])];
+        frame_bundle.alive_associations = Some(vec![0]);
+        frame_bundle.alive = vec![0, 1, 2];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.association_events[..], [AssociationEvent::New(_)]));
+        assert_eq!(client.source_list.read().unwrap()["test"].association_graph.get_children(0), vec![1, 2]);
+
+        frame_bundle.frame.frame_id = 2;
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(events.association_events.is_empty());
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(3, 640, 480, "test"));
+        frame_bundle.alive_associations = Some(vec![]);
+        frame_bundle.alive = vec![0, 1, 2];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.association_events[..], [AssociationEvent::Remove(_)]));
+        assert!(client.source_list.read().unwrap()["test"].association_graph.is_empty());
+    }
+
+    #[test]
+    fn tuio2_geometry_refresh() {
+        let client = Client::from_port(0).unwrap().with_protocol(Protocol::Tuio2);
+        let hull = vec![Position {x: 0., y: 0.}, Position {x: 1., y: 0.}, Position {x: 0., y: 1.}];
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.geometries = vec![Geometry::new(0).with_convex_hull(hull.clone()), Geometry::new(1).with_skeleton_volume(vec![0.1])];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.geometry_events[..], [GeometryEvent::New(GeometryData {ref geometry, ..})] if geometry.get_session_id() == 0));
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(2, 640, 480, "test"));
+        frame_bundle.geometries = vec![Geometry::new(0).with_skeleton_volume(vec![0.2])];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.geometry_events[..], [GeometryEvent::Update(GeometryData {ref geometry, ..})] if geometry.get_convex_hull() == Some(&hull[..]) && geometry.get_skeleton_volume() == Some(&[0.2][..])));
+    }
+
+    #[test]
+    fn channel_refresh() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        server.set_source_name("test");
+        let client = Client::from_osc_receiver(receiver);
+        client.connect().unwrap();
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        let start = Instant::now();
+
+        let events = loop {
+            match client.refresh() {
+                Some(events) if !events.cursor_events.is_empty() => break events,
+                _ => {
+                    assert!(start.elapsed() < Duration::from_secs(1), "no cursor received");
+                    thread::sleep(Duration::from_millis(1));
+                },
+            }
+        };
+
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(CursorData {ref source_name, ..})] if source_name == "test@local"));
+        client.disconnect();
+    }
+
+    #[test]
+    fn event_channel() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        server.set_source_name("test");
+        let client = Client::from_osc_receiver(receiver);
+        let events = client.events();
+        client.connect().unwrap();
+
+        assert!(client.wait_for_events(Duration::from_millis(10)).is_none());
+
+        server.init_frame();
+        let cursor_id = server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        assert!(client.wait_for_events(Duration::from_secs(1)).is_some());
+        assert!(matches!(events.try_recv(), Ok(TuioEvent::Cursor(CursorEvent::New(CursorData {ref cursor, ..}))) if cursor.get_session_id() == cursor_id));
+
+        drop(events);
+        server.init_frame();
+        server.remove_cursor(cursor_id);
+        server.commit_frame().unwrap();
+
+        assert!(client.wait_for_events(Duration::from_secs(1)).is_some());
+        assert!(client.state.event_senders.lock().unwrap().is_empty());
+        client.disconnect();
+    }
+
+    #[test]
+    fn end_of_session() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        server.set_source_name("test");
+        let client = Client::from_osc_receiver(receiver);
+        client.connect().unwrap();
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.create_object(1, 0.5, 0.5, 0.);
+        server.commit_frame().unwrap();
+        drop(server);
+
+        let mut events = TuioEvents::default();
+
+        while !events.source_events.iter().any(|event| matches!(event, SourceEvent::Remove(_))) {
+            events.append(client.wait_for_events(Duration::from_secs(1)).expect("no end of session received"));
+        }
+
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(_), CursorEvent::Remove(_)]));
+        assert!(matches!(events.object_events[..], [ObjectEvent::New(_), ObjectEvent::Remove(_)]));
+        assert!(matches!(events.source_events[..], [SourceEvent::New(_), SourceEvent::Remove(SourceData {ref source_name, ..})] if source_name == "test@local"));
+        assert!(client.source_list.read().unwrap().is_empty());
+        client.disconnect();
+    }
+
+    #[test]
+    fn source_info() {
+        let client = Client::from_port(0).unwrap().with_dual_stack();
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "tracker:2@192.168.1.5"));
+        frame_bundle.alive = vec![];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "test".into(), 1)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.source_events[..], [SourceEvent::New(_), SourceEvent::New(SourceData {ref source_name, ..})] if source_name == "test"));
+
+        let source_list = client.source_list.read().unwrap();
+        let info = &source_list["tracker:2@192.168.1.5"].info;
+        assert_eq!(info.get_name(), "tracker");
+        assert_eq!(info.get_host(), Some("192.168.1.5"));
+        assert_eq!(info.get_address(), Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 5))));
+        assert_eq!(info.get_protocol(), Protocol::Tuio2);
+        assert_eq!(source_list["test"].info.get_host(), None);
+
+        let mut info = SourceInfo::new("test", Protocol::Tuio1, Duration::ZERO);
+        info.update(Protocol::Tuio1, 10, Duration::ZERO);
+        info.update(Protocol::Tuio1, 40, Duration::from_millis(500));
+        assert_eq!(info.get_frame_rate(), 0.);
+        info.update(Protocol::Tuio1, 70, Duration::from_secs(1));
+        assert_eq!(info.get_frame_rate(), 60.);
+        assert_eq!(info.get_last_seen(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn source_timeout() {
+        let client = Client::from_port(0).unwrap().with_source_timeout(Duration::from_millis(50));
+
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "silent".into(), 1)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_object_bundle(&[Object::new(1, 2, Position {x: 0.1, y: 0.2}, 0.)], "silent".into(), 1)));
+        client.refresh().unwrap();
+        assert!(client.refresh().is_none());
+
+        thread::sleep(Duration::from_millis(60));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "active".into(), 1)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.cursor_events[..], [CursorEvent::Remove(CursorData {ref source_name, ..})] if source_name == "silent"));
+        assert!(matches!(events.object_events[..], [ObjectEvent::Remove(_)]));
+        assert!(matches!(events.source_events[..], [SourceEvent::New(_), SourceEvent::Remove(SourceData {ref source_name, ..})] if source_name == "silent"));
+        assert_eq!(client.source_list.read().unwrap().keys().collect::<Vec<_>>(), ["active"]);
+        assert!(client.state.frame_trackers.lock().unwrap().keys().all(|(source_name, _)| source_name == "active"));
+    }
+
+    #[test]
+    fn frameless_bundle() {
+        let client = Client::from_port(0).unwrap();
+
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "test".into(), 10)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.3, y: 0.2})], "test".into(), -1)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(_), CursorEvent::Update(_)]));
+        assert!(matches!(events.source_events[..], [SourceEvent::New(_)]));
+
+        // A goodbye from an unknown source is ignored
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "other".into(), -1)));
+        assert!(client.refresh().is_none());
+        assert_eq!(client.source_list.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn shared_snapshot() {
+        let client = Arc::new(Client::from_port(0).unwrap().with_protocol(Protocol::Tuio2));
+        assert!(client.snapshot().source_list.is_empty());
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.tokens = vec![Token::new(0, 4, Position {x: 0.1, y: 0.2}, 0.)];
+        frame_bundle.alive = vec![0];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+
+        let refreshing_client = Arc::clone(&client);
+        thread::spawn(move || refreshing_client.refresh()).join().unwrap();
+
+        let snapshot = client.snapshot();
+        assert_eq!(snapshot.source_list["test"].token_map.len(), 1);
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(2, 640, 480, "test"));
+        frame_bundle.alive = vec![];
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)));
+        client.refresh();
+
+        assert_eq!(snapshot.source_list["test"].token_map.len(), 1);
+        assert!(client.snapshot().source_list["test"].token_map.is_empty());
+    }
+
+    #[derive(Default)]
+    struct RecordingListener {
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl TuioListener for RecordingListener {
+        fn add_cursor(&self, data: &CursorData) {
+            self.calls.lock().unwrap().push(format!("add cursor {}", data.cursor.get_session_id()));
+        }
+
+        fn remove_cursor(&self, data: &CursorData) {
+            self.calls.lock().unwrap().push(format!("remove cursor {}", data.cursor.get_session_id()));
+        }
+
+        fn refresh(&self, _frame_time: Duration) {
+            self.calls.lock().unwrap().push("refresh".into());
+        }
+
+        fn packet_error(&self, _error: &TuioError) {
+            self.calls.lock().unwrap().push("error".into());
+        }
+    }
+
+    #[test]
+    fn malformed_packet_refresh() {
+        let client = Client::from_port(0).unwrap();
+        let listener = Arc::new(RecordingListener::default());
+        client.add_listener(listener.clone());
+
+        let malformed_bundle = OscBundle {timetag: (0, 0).into(), content: vec![OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::String("fseq".into())]})]};
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "first".into(), 1)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![]}));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(malformed_bundle));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(1, Position {x: 0.1, y: 0.2})], "second".into(), 1)));
+
+        let events = client.refresh().unwrap();
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(_), CursorEvent::New(_)]));
+        assert_eq!(*listener.calls.lock().unwrap(), ["error", "error", "add cursor 0", "refresh", "add cursor 1", "refresh"]);
+
+        let errors = client.take_errors();
+        assert!(matches!(errors[..], [TuioError::NotABundle(_), _]));
+        assert!(client.take_errors().is_empty());
+        assert_eq!(client.source_list.read().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn listener_on_refresh() {
+        let client = Client::from_port(0).unwrap();
+        let listener = Arc::new(RecordingListener::default());
+        let added_listener: Arc<dyn TuioListener> = listener.clone();
+        client.add_listener(added_listener.clone());
+
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.1, y: 0.2})], "test".into(), 1)));
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "test".into(), 2)));
+        client.refresh();
+
+        assert_eq!(*listener.calls.lock().unwrap(), ["add cursor 0", "refresh", "remove cursor 0", "refresh"]);
+
+        client.remove_listener(&added_listener);
+        client.packet_buffer.lock().unwrap().push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(1, Position {x: 0.1, y: 0.2})], "test".into(), 3)));
+        client.refresh();
+
+        assert_eq!(listener.calls.lock().unwrap().len(), 4);
+    }
+
+    /// Subscribes to the events of its client when a cursor is added
+    #[derive(Default)]
+    struct SubscribingListener {
+        client: OnceLock<Weak<Client>>,
+        events: Mutex<Option<Receiver<TuioEvent>>>,
+    }
+
+    impl TuioListener for SubscribingListener {
+        fn add_cursor(&self, _data: &CursorData) {
+            if let Some(client) = self.client.get().and_then(Weak::upgrade) {
+                *self.events.lock().unwrap() = Some(client.events());
+            }
+        }
+    }
+
+    #[test]
+    fn listener_subscribing_to_events() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        let client = Arc::new(Client::from_osc_receiver(receiver));
+        let listener = Arc::new(SubscribingListener::default());
+        listener.client.set(Arc::downgrade(&client)).unwrap();
+        client.add_listener(listener.clone());
+        client.connect().unwrap();
+
+        server.init_frame();
+        let cursor_id = server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        assert!(client.wait_for_events(Duration::from_secs(1)).is_some());
+        let events = listener.events.lock().unwrap().take().expect("listener not subscribed");
+
+        server.init_frame();
+        server.update_cursor(cursor_id, 0.6, 0.6);
+        server.commit_frame().unwrap();
+
+        assert!(client.wait_for_events(Duration::from_secs(1)).is_some());
+        assert!(matches!(events.try_iter().last(), Some(TuioEvent::Cursor(CursorEvent::Update(CursorData {ref cursor, ..}))) if cursor.get_session_id() == cursor_id));
+        client.disconnect();
+    }
+
+    #[test]
+    fn listener_on_receive_thread() {
+        let receiver = ChannelReceiver::new();
+        let mut server = Server::from_osc_sender(receiver.sender());
+        let client = Client::from_osc_receiver(receiver).with_listener_dispatch(ListenerDispatch::ReceiveThread);
+        let listener = Arc::new(RecordingListener::default());
+        client.add_listener(listener.clone());
+        client.connect().unwrap();
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        let start = Instant::now();
+
+        while listener.calls.lock().unwrap().len() < 2 {
+            assert!(start.elapsed() < Duration::from_secs(1), "listener not notified");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(listener.calls.lock().unwrap()[..2], ["add cursor 0", "refresh"]);
+        assert!(client.refresh().is_none());
+        client.disconnect();
+    }
+
+    #[test]
+    fn decode_error_reported() {
+        let receiver = UdpReceiver::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let target = receiver.local_addr().unwrap();
+        let client = Client::from_osc_receiver(receiver);
+        let listener = Arc::new(RecordingListener::default());
+        client.add_listener(listener.clone());
+        client.connect().unwrap();
+
+        std::net::UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap().send_to(b"not osc", target).unwrap();
+        let start = Instant::now();
+
+        // The error is kept by the receiving thread until the next refresh
+        while client.packet_buffer.lock().unwrap().is_empty() {
+            assert!(start.elapsed() < Duration::from_secs(1), "malformed packet not received");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert!(client.refresh().is_none());
+        assert!(matches!(client.take_errors()[..], [TuioError::Decode(_)]));
+        assert_eq!(*listener.calls.lock().unwrap(), ["error"]);
+        assert_eq!(client.get_connection_state(), ConnectionState::Connected);
+        client.disconnect();
+    }
+
+    #[test]
+    fn reconnect() {
+        let receiver = UdpReceiver::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let target = receiver.local_addr().unwrap();
+        let client = Client::from_osc_receiver(receiver);
+
+        client.connect().unwrap();
+        assert_eq!(client.get_connection_state(), ConnectionState::Connected);
+        assert!(matches!(client.connect(), Err(OscReceiverError::AlreadyConnected())));
+
+        let start = Instant::now();
+        client.disconnect();
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(client.get_connection_state(), ConnectionState::Disconnected);
+
+        client.connect().unwrap();
+
+        let mut server = Server::from_socket_addr(target).unwrap();
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        let start = Instant::now();
+
+        while client.refresh().is_none() {
+            assert!(start.elapsed() < Duration::from_secs(1), "no frame received after reconnecting");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn interrupted_connection() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0), Framing::Slip).unwrap();
+        let client = Client::from_osc_receiver(TcpReceiver::new(sender.local_addr(), Framing::Slip));
+        client.connect().unwrap();
+        let start = Instant::now();
+
+        while sender.client_count() == 0 {
+            assert!(start.elapsed() < Duration::from_secs(1), "connection not accepted");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        drop(sender);
+        let start = Instant::now();
+
+        while client.get_connection_state() != ConnectionState::Interrupted {
+            assert!(start.elapsed() < Duration::from_secs(1), "closed connection not reported");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        client.disconnect();
+        assert_eq!(client.get_connection_state(), ConnectionState::Disconnected);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_stream() {
+        use crate::{osc_receiver::AsyncUdpReceiver, server::AsyncUdpSender};
+
+        let receiver = AsyncUdpReceiver::from_port(0).unwrap();
+        let target = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), receiver.local_addr().unwrap().port());
+        let mut client = AsyncClient::from_async_osc_receiver(receiver);
+        client.connect().await.unwrap();
+
+        let mut server = Server::from_async_osc_sender(AsyncUdpSender::new(target).unwrap());
+        server.set_source_name("test");
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+
+        // Spawning the frame's commit checks that the server can be moved across tokio tasks
+        let mut server = tokio::spawn(async move {
+            server.commit_frame_async().await.unwrap();
+            server
+        }).await.unwrap();
+
+        let events = client.next_events().await.unwrap();
+        assert!(matches!(events.cursor_events[..], [CursorEvent::New(CursorData {ref source_name, ..})] if source_name == "test@local"));
+        assert_eq!(client.get_client().source_list.read().unwrap()["test@local"].cursor_map.len(), 1);
+
+        // The end of session only reaches async senders through close
+        server.close().await.unwrap();
+
+        // Every profile ends its session in its own bundle, the cursor one being second
+        let events = loop {
+            let events = client.next_events().await.unwrap();
+
+            if !events.cursor_events.is_empty() {
+                break events;
+            }
+        };
+
+        assert!(matches!(events.cursor_events[..], [CursorEvent::Remove(_)]));
+        assert!(client.get_client().source_list.read().unwrap().is_empty());
+
+        client.disconnect();
+        assert!(client.next_events().await.is_none());
+    }
+
+    #[cfg(feature = "async")]
+    struct FailingAsyncReceiver;
+
+    #[cfg(feature = "async")]
+    impl AsyncOscReceiver<OscPacket, OscReceiverError> for FailingAsyncReceiver {
+        fn is_connected(&self) -> bool {
+            false
+        }
+
+        fn connect(&self) -> crate::osc_receiver::BoxFuture<'_, Result<(), std::io::Error>> {
+            Box::pin(async {Err(std::io::Error::from(std::io::ErrorKind::AddrInUse))})
+        }
+
+        fn disconnect(&self) {}
+
+        fn recv(&self) -> crate::osc_receiver::BoxFuture<'_, Result<OscPacket, OscReceiverError>> {
+            Box::pin(std::future::pending())
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_connect_failure() {
+        use crate::osc_receiver::AsyncUdpReceiver;
+
+        let mut client = AsyncClient::from_async_osc_receiver(AsyncUdpReceiver::from_port(0).unwrap());
+        client.add_async_osc_receiver(FailingAsyncReceiver);
+
+        // The tasks of the receivers connected before the failure are stopped, not spawned again by a retry
+        for _ in 0..2 {
+            assert!(matches!(client.connect().await, Err(OscReceiverError::Connect(_))));
+            assert!(client.tasks.is_empty());
+            assert!(client.packet_receiver.is_none());
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::time::Duration;
 
-#[derive(Default, Debug, Clone)]
+use crate::tuio2::Pointer;
+
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct Position {
     pub x: f32,
     pub y: f32,
@@ -26,6 +28,49 @@ impl Velocity {
     }
 }
 
+#[derive(Default, Debug, Clone)]
+pub struct Position3D {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Position3D {
+    pub fn distance_from(&self, position: &Position3D) -> f32 {
+        let dx = self.x - position.x;
+        let dy = self.y - position.y;
+        let dz = self.z - position.z;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct Velocity3D {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Velocity3D {
+    pub fn get_speed(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+/// Euler angles (or per-axis rotation speeds) around the x, y and z axes
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct Angle3D {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+}
+
+impl Angle3D {
+    pub fn get_magnitude(&self) -> f32 {
+        (self.a * self.a + self.b * self.b + self.c * self.c).sqrt()
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Cursor {
     pub(crate) session_id: i32,
@@ -123,6 +168,18 @@ impl PartialEq for Cursor {
     }
 }
 
+impl From<Pointer> for Cursor {
+    /// Converts a TUIO 2 [Pointer] into its TUIO 1.1 equivalent
+    fn from(pointer: Pointer) -> Self {
+        Self {
+            session_id: pointer.session_id,
+            position: pointer.position,
+            velocity: pointer.velocity,
+            acceleration: pointer.acceleration,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{f32::consts::SQRT_2, time::Duration};
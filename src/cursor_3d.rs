@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::cursor::{Position3D, Velocity3D};
+
+#[derive(Debug, Clone, Default)]
+pub struct Cursor3D {
+    pub(crate) session_id: i32,
+    pub(crate) position: Position3D,
+    pub(crate) velocity: Velocity3D,
+    pub(crate) acceleration: f32,
+}
+
+impl Cursor3D {
+    /// Creates a new [Cursor3D]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `position` - a normalized [Position3D]
+    pub fn new(session_id: i32, position: Position3D) -> Self {
+        Self {
+            session_id,
+            position,
+            velocity: Velocity3D::default(),
+            acceleration: 0f32,
+        }
+    }
+
+    /// Returns this [Cursor3D] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity3D]
+    /// * `acceleration` - a normalized acceleration
+    pub fn with_motion(mut self, velocity: Velocity3D, acceleration: f32) -> Self {
+        self.velocity = velocity;
+        self.acceleration = acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_position(&self) -> &Position3D {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_z_position(&self) -> f32 {
+        self.position.z
+    }
+
+    pub fn get_velocity(&self) -> &Velocity3D {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_z_velocity(&self) -> f32 {
+        self.velocity.z
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Updates the [Cursor3D], computing its velocity and acceleration
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position3D]
+    pub fn update(&mut self, delta_time: Duration, position: Position3D) {
+        let delta_time = delta_time.as_secs_f32();
+        let distance = position.distance_from(&self.position);
+
+        let last_speed = self.velocity.get_speed();
+        let speed = distance / delta_time;
+
+        self.velocity = Velocity3D {
+            x: (position.x - self.position.x) / delta_time,
+            y: (position.y - self.position.y) / delta_time,
+            z: (position.z - self.position.z) / delta_time,
+        };
+
+        self.acceleration = (speed - last_speed) / delta_time;
+        self.position = position;
+    }
+}
+
+impl PartialEq for Cursor3D {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.get_z_position() == other.get_z_position()
+            && self.velocity == other.velocity
+            && self.acceleration == other.acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{cursor::Position3D, cursor_3d::Cursor3D};
+
+    #[test]
+    fn cursor_3d_update() {
+        let mut cursor = Cursor3D::new(0, Position3D { x: 0., y: 0., z: 0. });
+
+        cursor.update(Duration::from_secs(1), Position3D { x: 1., y: 1., z: 1. });
+
+        assert_eq!(cursor.get_x_position(), 1.);
+        assert_eq!(cursor.get_y_position(), 1.);
+        assert_eq!(cursor.get_z_position(), 1.);
+        assert_eq!(cursor.get_x_velocity(), 1.);
+        assert_eq!(cursor.get_y_velocity(), 1.);
+        assert_eq!(cursor.get_z_velocity(), 1.);
+        assert_eq!(cursor.get_acceleration(), 3f32.sqrt());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::errors::CustomProfileError;
+
+/// A value of a custom profile attribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CustomValue {
+    Int(i32),
+    Float(f32),
+}
+
+impl CustomValue {
+    /// Returns the value as an [i32] if it is an integer
+    pub fn int(&self) -> Option<i32> {
+        match self {
+            CustomValue::Int(value) => Some(*value),
+            CustomValue::Float(_) => None,
+        }
+    }
+
+    /// Returns the value as an [f32] if it is a float
+    pub fn float(&self) -> Option<f32> {
+        match self {
+            CustomValue::Int(_) => None,
+            CustomValue::Float(value) => Some(*value),
+        }
+    }
+}
+
+/// Returns true if a format character describes an integer attribute
+///
+/// Only the session ID `s` and the class ID `i` are integers, every other attribute is a float
+pub(crate) fn is_int_attribute(attribute: char) -> bool {
+    attribute == 's' || attribute == 'i'
+}
+
+/// Checks that a format string can be decoded and that values match it
+///
+/// The format must be ASCII, start with the session ID `s` and contain no OSC address pattern character, and there
+/// must be one value per attribute after the session ID, integers for [is_int_attribute] ones and floats otherwise
+/// # Arguments
+/// * `format` - the profile's format string
+/// * `values` - the attribute values following the session ID
+pub(crate) fn validate(format: &str, values: &[CustomValue]) -> Result<(), CustomProfileError> {
+    if !format.starts_with('s') || !format.is_ascii() || format.contains(|c: char| c.is_ascii_control() || " #*,/?[]{}".contains(c)) {
+        return Err(CustomProfileError::InvalidFormat(format.into()));
+    }
+
+    if values.len() != format.len() - 1 {
+        return Err(CustomProfileError::WrongValueCount(format.into(), values.len()));
+    }
+
+    match format.chars().skip(1).zip(values).position(|(attribute, value)| is_int_attribute(attribute) != value.int().is_some()) {
+        Some(index) => Err(CustomProfileError::WrongValueType(format.into(), index)),
+        None => Ok(()),
+    }
+}
+
+/// An entity of a custom `/tuio/_[format]` profile
+///
+/// The format string lists one character per "set" argument, starting with the session ID `s`.
+/// For example `/tuio/_sixyP` carries a session ID, a class ID, a position and a custom `P` attribute.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomProfile {
+    pub(crate) session_id: i32,
+    pub(crate) format: String,
+    pub(crate) values: Vec<CustomValue>,
+}
+
+impl CustomProfile {
+    /// Creates a new [CustomProfile]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `format` - the profile's format string, starting with `s`
+    /// * `values` - the attribute values following the session ID, in the order of the format string
+    pub fn new(session_id: i32, format: &str, values: Vec<CustomValue>) -> Self {
+        Self {
+            session_id,
+            format: format.into(),
+            values,
+        }
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    /// Returns the format string
+    pub fn get_format(&self) -> &str {
+        &self.format
+    }
+
+    /// Returns the attribute values following the session ID, in the order of the format string
+    pub fn get_values(&self) -> &[CustomValue] {
+        &self.values
+    }
+
+    /// Returns the value of the first attribute matching a format character
+    /// # Arguments
+    /// * `attribute` - the attribute's format character
+    pub fn get(&self, attribute: char) -> Option<CustomValue> {
+        let index = self.format.chars().skip(1).position(|c| c == attribute)?;
+        self.values.get(index).copied()
+    }
+
+    /// Updates the [CustomProfile]'s values
+    /// # Arguments
+    /// * `values` - the new attribute values following the session ID
+    pub fn update(&mut self, values: Vec<CustomValue>) {
+        self.values = values;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{custom_profile::{CustomProfile, CustomValue, validate}, errors::CustomProfileError};
+
+    #[test]
+    fn custom_profile_validate() {
+        assert!(validate("sixyP", &[CustomValue::Int(3), CustomValue::Float(0.1), CustomValue::Float(0.2), CustomValue::Float(0.5)]).is_ok());
+        assert!(matches!(validate("xy", &[CustomValue::Float(0.1)]), Err(CustomProfileError::InvalidFormat(_))));
+        assert!(matches!(validate("s/x", &[CustomValue::Float(0.1), CustomValue::Float(0.1)]), Err(CustomProfileError::InvalidFormat(_))));
+        assert!(matches!(validate("sxy", &[CustomValue::Float(0.1)]), Err(CustomProfileError::WrongValueCount(_, 1))));
+        assert!(matches!(validate("sixy", &[CustomValue::Float(1.), CustomValue::Float(0.1), CustomValue::Float(0.2)]), Err(CustomProfileError::WrongValueType(_, 0))));
+    }
+
+    #[test]
+    fn custom_profile_get() {
+        let profile = CustomProfile::new(0, "sixyP", vec![CustomValue::Int(3), CustomValue::Float(0.1), CustomValue::Float(0.2), CustomValue::Float(0.5)]);
+
+        assert_eq!(profile.get('i'), Some(CustomValue::Int(3)));
+        assert_eq!(profile.get('y').and_then(|value| value.float()), Some(0.2));
+        assert_eq!(profile.get('P'), Some(CustomValue::Float(0.5)));
+        assert_eq!(profile.get('z'), None);
+    }
+}
@@ -12,6 +12,7 @@ pub enum TuioError {
     WrongArgumentType(OscMessage, u8),
     IncompleteBundle(OscBundle),
     NotABundle(OscPacket),
+    Decode(OscError),
 }
 
 impl fmt::Display for TuioError {
@@ -25,6 +26,7 @@ impl fmt::Display for TuioError {
             TuioError::WrongArgumentType(msg, index) => write!(f, "wrong argument type at index {} in: {:?}", index, msg),
             TuioError::IncompleteBundle(bundle) => write!(f, "missing one or more mandatory messages in: {:?}", bundle),
             TuioError::NotABundle(packet) => write!(f, "OSC packet is not a bundle: {:?}", packet),
+            TuioError::Decode(err) => write!(f, "error decoding OSC packet: {}", err),
         }
     }
 }
@@ -58,3 +60,46 @@ impl error::Error for OscReceiverError {
         }
     }
 }
+
+#[derive(Debug)]
+pub enum OscSenderError {
+    Encode(OscError),
+    Send(io::Error),
+}
+
+impl fmt::Display for OscSenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OscSenderError::Encode(msg) => write!(f, "error encoding OSC packet: {}", msg),
+            OscSenderError::Send(msg) => write!(f, "error sending OSC packet: {}", msg),
+        }
+    }
+}
+
+impl error::Error for OscSenderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            OscSenderError::Encode(err) => Some(err),
+            OscSenderError::Send(err) => Some(err)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CustomProfileError {
+    InvalidFormat(String),
+    WrongValueCount(String, usize),
+    WrongValueType(String, usize),
+}
+
+impl fmt::Display for CustomProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomProfileError::InvalidFormat(format) => write!(f, "invalid custom profile format, expected an ASCII format starting with 's': {:?}", format),
+            CustomProfileError::WrongValueCount(format, count) => write!(f, "{} values given for custom profile format {:?}, expected {}", count, format, format.len() - 1),
+            CustomProfileError::WrongValueType(format, index) => write!(f, "wrong value type at index {} for custom profile format {:?}", index, format),
+        }
+    }
+}
+
+impl error::Error for CustomProfileError {}
@@ -0,0 +1,117 @@
+use std::io::{self, BufRead, ErrorKind};
+
+const SLIP_END: u8 = 0xC0;
+const SLIP_ESC: u8 = 0xDB;
+const SLIP_ESC_END: u8 = 0xDC;
+const SLIP_ESC_ESC: u8 = 0xDD;
+
+/// The framing of OSC packets over stream transports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Framing {
+    /// OSC 1.0 framing, each packet being prefixed by its size as a big-endian int32
+    #[default]
+    LengthPrefix,
+    /// OSC 1.1 framing, each packet being SLIP encoded (RFC 1055) between two END bytes
+    Slip,
+}
+
+impl Framing {
+    /// Frames an encoded OSC packet
+    ///
+    /// # Arguments
+    /// * `packet` - the encoded OSC packet
+    pub fn encode(&self, packet: &[u8]) -> Vec<u8> {
+        match self {
+            Framing::LengthPrefix => {
+                let mut buffer = Vec::with_capacity(packet.len() + 4);
+                buffer.extend((packet.len() as i32).to_be_bytes());
+                buffer.extend(packet);
+                buffer
+            },
+            Framing::Slip => {
+                let mut buffer = Vec::with_capacity(packet.len() + 2);
+                buffer.push(SLIP_END);
+
+                for byte in packet {
+                    match *byte {
+                        SLIP_END => buffer.extend([SLIP_ESC, SLIP_ESC_END]),
+                        SLIP_ESC => buffer.extend([SLIP_ESC, SLIP_ESC_ESC]),
+                        byte => buffer.push(byte),
+                    }
+                }
+
+                buffer.push(SLIP_END);
+                buffer
+            },
+        }
+    }
+
+    /// Reads a single framed OSC packet
+    ///
+    /// Returns an [io::Error] of kind [ErrorKind::UnexpectedEof] when the stream ends
+    /// # Arguments
+    /// * `reader` - a buffered stream
+    pub fn read_packet(&self, reader: &mut impl BufRead) -> io::Result<Vec<u8>> {
+        match self {
+            Framing::LengthPrefix => {
+                let mut size = [0u8; 4];
+                reader.read_exact(&mut size)?;
+
+                let size = usize::try_from(i32::from_be_bytes(size)).map_err(|_| io::Error::new(ErrorKind::InvalidData, "negative OSC packet size"))?;
+                let mut packet = vec![0u8; size];
+                reader.read_exact(&mut packet)?;
+                Ok(packet)
+            },
+            Framing::Slip => loop {
+                let mut frame = Vec::new();
+
+                if reader.read_until(SLIP_END, &mut frame)? == 0 || frame.pop() != Some(SLIP_END) {
+                    return Err(ErrorKind::UnexpectedEof.into());
+                }
+
+                // Skips the empty frames between two consecutive END bytes
+                if frame.is_empty() {
+                    continue;
+                }
+
+                let mut packet = Vec::with_capacity(frame.len());
+                let mut bytes = frame.into_iter();
+
+                while let Some(byte) = bytes.next() {
+                    packet.push(if byte == SLIP_ESC {
+                        match bytes.next() {
+                            Some(SLIP_ESC_END) => SLIP_END,
+                            Some(SLIP_ESC_ESC) => SLIP_ESC,
+                            _ => return Err(io::Error::new(ErrorKind::InvalidData, "invalid SLIP escape sequence")),
+                        }
+                    } else {
+                        byte
+                    });
+                }
+
+                return Ok(packet);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, ErrorKind};
+
+    use super::*;
+
+    #[test]
+    fn framing() {
+        let packets = [vec![1, 2, 3], vec![SLIP_END, 0, SLIP_ESC, SLIP_ESC_END]];
+
+        for framing in [Framing::LengthPrefix, Framing::Slip] {
+            let stream: Vec<u8> = packets.iter().flat_map(|packet| framing.encode(packet)).collect();
+            let mut reader = BufReader::new(&stream[..]);
+
+            assert_eq!(framing.read_packet(&mut reader).unwrap(), packets[0]);
+            assert_eq!(framing.read_packet(&mut reader).unwrap(), packets[1]);
+            assert_eq!(framing.read_packet(&mut reader).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        }
+    }
+}
@@ -1,14 +1,31 @@
 mod object;
 mod blob;
-mod errors;
+mod cursor_25d;
+mod object_25d;
+mod blob_25d;
+mod cursor_3d;
+mod object_3d;
+mod blob_3d;
+mod custom_profile;
+pub mod errors;
 pub mod server;
 pub mod client;
 pub mod cursor;
 pub mod osc_encode_decode;
 pub mod osc_receiver;
+pub mod framing;
+pub mod tuio2;
+pub mod translator;
 
 pub use server::Server;
 pub use client::Client;
 pub use cursor::Cursor;
 pub use object::Object;
-pub use blob::Blob;
\ No newline at end of file
+pub use blob::Blob;
+pub use cursor_25d::Cursor25D;
+pub use object_25d::Object25D;
+pub use blob_25d::Blob25D;
+pub use cursor_3d::Cursor3D;
+pub use object_3d::Object3D;
+pub use blob_3d::Blob3D;
+pub use custom_profile::{CustomProfile, CustomValue};
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::{f32::consts::PI, time::Duration};
 
-use crate::cursor::{Position, Velocity};
+use crate::{cursor::{Position, Velocity}, tuio2::Token};
 
 #[derive(Debug, Clone, Default)]
 pub struct Object {
@@ -150,6 +150,22 @@ impl PartialEq for Object {
     }
 }
 
+impl From<Token> for Object {
+    /// Converts a TUIO 2 [Token] into its TUIO 1.1 equivalent
+    fn from(token: Token) -> Self {
+        Self {
+            session_id: token.session_id,
+            class_id: token.component_id,
+            position: token.position,
+            angle: token.angle,
+            velocity: token.velocity,
+            rotation_speed: token.rotation_speed,
+            acceleration: token.acceleration,
+            rotation_acceleration: token.rotation_acceleration,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{f32::consts::SQRT_2, time::Duration};
@@ -0,0 +1,191 @@
+use std::{f32::consts::PI, time::Duration};
+
+use crate::cursor::{Angle3D, Position3D, Velocity3D};
+
+#[derive(Debug, Clone, Default)]
+pub struct Object3D {
+    pub(crate) session_id: i32,
+    pub(crate) class_id: i32,
+    pub(crate) position: Position3D,
+    pub(crate) angle: Angle3D,
+    pub(crate) velocity: Velocity3D,
+    pub(crate) rotation_speed: Angle3D,
+    pub(crate) acceleration: f32,
+    pub(crate) rotation_acceleration: f32,
+}
+
+impl Object3D {
+    /// Creates a new [Object3D]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `class_id` - the object's class ID
+    /// * `position` - a normalized [Position3D]
+    /// * `angle` - the Euler angles in radians
+    pub fn new(session_id: i32, class_id: i32, position: Position3D, angle: Angle3D) -> Self {
+        Self {
+            session_id,
+            class_id,
+            position,
+            velocity: Velocity3D::default(),
+            acceleration: 0f32,
+            angle,
+            rotation_speed: Angle3D::default(),
+            rotation_acceleration: 0f32,
+        }
+    }
+
+    /// Returns this [Object3D] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity3D]
+    /// * `rotation_speed` - the rotation speeds around each axis in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a roation acceleration in radians turn per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity3D,
+        rotation_speed: Angle3D,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_class_id(&self) -> i32 {
+        self.class_id
+    }
+
+    pub fn get_position(&self) -> &Position3D {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_z_position(&self) -> f32 {
+        self.position.z
+    }
+
+    pub fn get_velocity(&self) -> &Velocity3D {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_z_velocity(&self) -> f32 {
+        self.velocity.z
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the Euler angles in radians
+    pub fn get_angle(&self) -> &Angle3D {
+        &self.angle
+    }
+
+    /// Returns the rotation speeds around each axis in turn per seconds
+    pub fn get_rotation_speed(&self) -> &Angle3D {
+        &self.rotation_speed
+    }
+
+    /// Returns the rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Updates the [Object3D], computing its velocity, acceleration, rotation speed and rotation acceleration
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position3D]
+    /// * `angle` - the new Euler angles
+    pub fn update(&mut self, delta_time: Duration, position: Position3D, angle: Angle3D) {
+        let delta_time = delta_time.as_secs_f32();
+        let distance = position.distance_from(&self.position);
+
+        let last_speed = self.velocity.get_speed();
+        let speed = distance / delta_time;
+
+        self.velocity = Velocity3D {
+            x: (position.x - self.position.x) / delta_time,
+            y: (position.y - self.position.y) / delta_time,
+            z: (position.z - self.position.z) / delta_time,
+        };
+
+        self.acceleration = (speed - last_speed) / delta_time;
+        self.position = position;
+
+        let rotation_speed = Angle3D {
+            a: (angle.a - self.angle.a) / (2. * PI) / delta_time,
+            b: (angle.b - self.angle.b) / (2. * PI) / delta_time,
+            c: (angle.c - self.angle.c) / (2. * PI) / delta_time,
+        };
+
+        self.rotation_acceleration = (rotation_speed.get_magnitude() - self.rotation_speed.get_magnitude()) / delta_time;
+        self.rotation_speed = rotation_speed;
+        self.angle = angle;
+    }
+}
+
+impl PartialEq for Object3D {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.class_id == other.class_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.get_z_position() == other.get_z_position()
+            && self.angle == other.angle
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{cursor::{Angle3D, Position3D}, object_3d::Object3D};
+
+    #[test]
+    fn object_3d_update() {
+        let mut object = Object3D::new(0, 0, Position3D { x: 0., y: 0., z: 0. }, Angle3D::default());
+
+        object.update(
+            Duration::from_secs(1),
+            Position3D { x: 1., y: 1., z: 1. },
+            Angle3D { a: 90f32.to_radians(), b: 0., c: 0. },
+        );
+
+        assert_eq!(object.get_x_position(), 1.);
+        assert_eq!(object.get_y_position(), 1.);
+        assert_eq!(object.get_z_position(), 1.);
+        assert_eq!(object.get_x_velocity(), 1.);
+        assert_eq!(object.get_y_velocity(), 1.);
+        assert_eq!(object.get_z_velocity(), 1.);
+        assert_eq!(object.get_acceleration(), 3f32.sqrt());
+        assert_eq!(object.get_angle().a, 90f32.to_radians());
+        assert_eq!(*object.get_rotation_speed(), Angle3D { a: 0.25, b: 0., c: 0. });
+        assert_eq!(object.get_rotation_acceleration(), 0.25);
+    }
+}
@@ -2,7 +2,7 @@ use std::{time::{SystemTime}, iter};
 
 use rosc::{OscBundle, OscPacket, OscMessage, OscType, OscTime};
 
-use crate::{object::Object, cursor::{Cursor, Position, Velocity}, blob::Blob, errors::TuioError};
+use crate::{object::Object, cursor::{Cursor, Position, Velocity, Position3D, Velocity3D, Angle3D}, blob::Blob, cursor_25d::Cursor25D, object_25d::Object25D, blob_25d::Blob25D, cursor_3d::Cursor3D, object_3d::Object3D, blob_3d::Blob3D, custom_profile::{CustomProfile, CustomValue, is_int_attribute}, errors::TuioError};
 
 /// Base trait to implement an OSC encoder
 pub trait EncodeOsc<T> {
@@ -26,176 +26,325 @@ pub trait EncodeOsc<T> {
     /// * `source_name` - the source's name
     /// * `frame_id` - the current's frame id
     fn encode_blob_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Blob>;
+
+    /// Encodes an [Object25D] collection into an OSC bundle
+    /// # Arguments
+    /// * `object_collection` - an iterable [Object25D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_object_25d_bundle<'a, I>(object_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Object25D>;
+
+    /// Encodes an [Cursor25D] collection into an OSC bundle
+    /// # Arguments
+    /// * `cursor_collection` - an iterable [Cursor25D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_cursor_25d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Cursor25D>;
+
+    /// Encodes an [Blob25D] collection into an OSC bundle
+    /// # Arguments
+    /// * `blob_collection` - an iterable [Blob25D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_blob_25d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Blob25D>;
+
+    /// Encodes an [Object3D] collection into an OSC bundle
+    /// # Arguments
+    /// * `object_collection` - an iterable [Object3D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_object_3d_bundle<'a, I>(object_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Object3D>;
+
+    /// Encodes an [Cursor3D] collection into an OSC bundle
+    /// # Arguments
+    /// * `cursor_collection` - an iterable [Cursor3D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_cursor_3d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Cursor3D>;
+
+    /// Encodes an [Blob3D] collection into an OSC bundle
+    /// # Arguments
+    /// * `blob_collection` - an iterable [Blob3D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_blob_3d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Blob3D>;
+
+    /// Encodes a [CustomProfile] collection sharing the same format into an OSC bundle
+    /// # Arguments
+    /// * `custom_collection` - an iterable [CustomProfile] collection
+    /// * `format` - the profile's format string, sent as `/tuio/_[format]`
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_custom_bundle<'a, I>(custom_collection: I, format: &str, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a CustomProfile>;
 }
 
 /// An implementation of trait [EncodeOsc] based on [rosc]
 pub struct OscEncoder;
 
+/// Builds a TUIO bundle made of a source, an alive, several set and a fseq message
+///
+/// # Arguments
+/// * `address` - the profile's OSC address
+/// * `source_name` - the source's name
+/// * `frame_id` - the current's frame id
+/// * `alive` - the session ids of every entity of the profile
+/// * `set_args` - an iterator over the session id and "set" arguments of the entities to send
+pub(crate) fn encode_bundle<A, I>(address: &str, source_name: String, frame_id: i32, alive: A, set_args: I) -> OscBundle where A: IntoIterator<Item = i32>, I: IntoIterator<Item = (i32, Vec<OscType>)> {
+    let source_message = OscPacket::Message(OscMessage {
+        addr: address.into(),
+        args: vec![
+            OscType::String("source".into()),
+            OscType::String(source_name)
+        ]
+    });
+
+    let set_messages = set_args.into_iter().map(|(id, args)| OscPacket::Message(OscMessage {
+        addr: address.into(),
+        args: vec![OscType::String("set".into()), OscType::Int(id)].into_iter().chain(args).collect()
+    }));
+
+    let alive_message = OscPacket::Message(OscMessage {
+        addr: address.into(),
+        args: iter::once(OscType::String("alive".into())).chain(alive.into_iter().map(OscType::Int)).collect()
+    });
+
+    let frame_message = OscPacket::Message(OscMessage {
+        addr: address.into(),
+        args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
+    });
+
+    OscBundle {
+        timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"),
+        content: vec![
+            source_message,
+            alive_message
+        ].into_iter()
+        .chain(set_messages)
+        .chain(iter::once(frame_message))
+        .collect()
+    }
+}
+
+/// Builds a TUIO bundle sending every entity of a collection, which is then the whole alive list
+fn encode_full_bundle<'a, E, I>(address: &str, source_name: String, frame_id: i32, collection: I) -> OscBundle where E: EncodeSet + 'a, I: IntoIterator<Item = &'a E> {
+    let set_args: Vec<(i32, Vec<OscType>)> = collection.into_iter().map(EncodeSet::encode_set).collect();
+    let alive: Vec<i32> = set_args.iter().map(|(id, _)| *id).collect();
+    encode_bundle(address, source_name, frame_id, alive, set_args)
+}
+
+/// An entity sent through "set" messages
+pub(crate) trait EncodeSet {
+    /// Returns the session id and the "set" arguments of the entity
+    fn encode_set(&self) -> (i32, Vec<OscType>);
+}
+
+impl EncodeSet for Object {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Int(self.get_class_id()),
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_angle()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_rotation_speed()),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Cursor {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Blob {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_angle()),
+            OscType::Float(self.get_width()),
+            OscType::Float(self.get_height()),
+            OscType::Float(self.get_area()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_rotation_speed()),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Object25D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Int(self.get_class_id()),
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_angle()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_rotation_speed()),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Cursor25D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Blob25D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_angle()),
+            OscType::Float(self.get_width()),
+            OscType::Float(self.get_height()),
+            OscType::Float(self.get_area()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_rotation_speed()),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Object3D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Int(self.get_class_id()),
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_angle().a),
+            OscType::Float(self.get_angle().b),
+            OscType::Float(self.get_angle().c),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_rotation_speed().a),
+            OscType::Float(self.get_rotation_speed().b),
+            OscType::Float(self.get_rotation_speed().c),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Cursor3D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for Blob3D {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), vec![
+            OscType::Float(self.get_x_position()),
+            OscType::Float(self.get_y_position()),
+            OscType::Float(self.get_z_position()),
+            OscType::Float(self.get_angle().a),
+            OscType::Float(self.get_angle().b),
+            OscType::Float(self.get_angle().c),
+            OscType::Float(self.get_width()),
+            OscType::Float(self.get_height()),
+            OscType::Float(self.get_depth()),
+            OscType::Float(self.get_volume()),
+            OscType::Float(self.get_x_velocity()),
+            OscType::Float(self.get_y_velocity()),
+            OscType::Float(self.get_z_velocity()),
+            OscType::Float(self.get_rotation_speed().a),
+            OscType::Float(self.get_rotation_speed().b),
+            OscType::Float(self.get_rotation_speed().c),
+            OscType::Float(self.get_acceleration()),
+            OscType::Float(self.get_rotation_acceleration())
+        ])
+    }
+}
+
+impl EncodeSet for CustomProfile {
+    fn encode_set(&self) -> (i32, Vec<OscType>) {
+        (self.get_session_id(), self.get_values().iter().map(|value| match value {
+            CustomValue::Int(value) => OscType::Int(*value),
+            CustomValue::Float(value) => OscType::Float(*value),
+        }).collect())
+    }
+}
+
 impl EncodeOsc<OscBundle> for OscEncoder {
     fn encode_object_bundle<'a, I>(object_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Object> {
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(source_name)
-            ]
-        });
-    
-        let mut set_messages = vec![];
-        let mut object_ids: Vec<OscType>= vec![];
-    
-        for object in object_collection.into_iter()  {
-            let id = object.get_session_id();
-            object_ids.push(OscType::Int(id));
-    
-            set_messages.push(OscPacket::Message(OscMessage {
-                addr: "/tuio/2Dobj".into(),
-                args: vec![
-                    OscType::String("set".into()),
-                    OscType::Int(id),
-                    OscType::Int(object.get_class_id()),
-                    OscType::Float(object.get_x_position()),
-                    OscType::Float(object.get_y_position()),
-                    OscType::Float(object.get_angle()),
-                    OscType::Float(object.get_x_velocity()),
-                    OscType::Float(object.get_y_velocity()),
-                    OscType::Float(object.get_rotation_speed()),
-                    OscType::Float(object.get_acceleration()),
-                    OscType::Float(object.get_rotation_acceleration())
-                ]
-            }));
-        }
-        
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(object_ids.into_iter()).collect()
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
-        });
-    
-        OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-            content: vec![
-                source_message,
-                alive_message
-            ].into_iter()
-            .chain(set_messages.into_iter())
-            .chain(iter::once(frame_message))
-            .collect()
-        }
+        encode_full_bundle("/tuio/2Dobj", source_name, frame_id, object_collection)
     }
 
     fn encode_cursor_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Cursor> {
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(source_name)
-            ]
-        });
-    
-        let mut set_messages = vec![];
-        let mut cursor_ids: Vec<OscType>= vec![];
-    
-        for cursor in cursor_collection.into_iter()  {
-            let id = cursor.get_session_id();
-            cursor_ids.push(OscType::Int(id));
-
-            set_messages.push(OscPacket::Message(OscMessage {
-                addr: "/tuio/2Dcur".into(),
-                args: vec![
-                    OscType::String("set".into()),
-                    OscType::Int(id),
-                    OscType::Float(cursor.get_x_position()),
-                    OscType::Float(cursor.get_y_position()),
-                    OscType::Float(cursor.get_x_velocity()),
-                    OscType::Float(cursor.get_y_velocity()),
-                    OscType::Float(cursor.get_acceleration())
-                ]
-            }));
-        }
-        
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(cursor_ids.into_iter()).collect()
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
-        });
-    
-        OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-            content: vec![
-                source_message,
-                alive_message
-            ].into_iter()
-            .chain(set_messages.into_iter())
-            .chain(iter::once(frame_message))
-            .collect()
-        }
+        encode_full_bundle("/tuio/2Dcur", source_name, frame_id, cursor_collection)
     }
 
     fn encode_blob_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Blob> {
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(source_name)
-            ]
-        });
-    
-        let mut set_messages = vec![];
-        let mut blob_ids: Vec<OscType>= vec![];
-    
-        for blob in blob_collection.into_iter() {     
-            let id = blob.get_session_id();       
-            blob_ids.push(OscType::Int(id));
-            
-            set_messages.push(OscPacket::Message(OscMessage {
-                addr: "/tuio/2Dblb".into(),
-                args: vec![
-                    OscType::String("set".into()),
-                    OscType::Int(id),
-                    OscType::Float(blob.get_x_position()),
-                    OscType::Float(blob.get_y_position()),
-                    OscType::Float(blob.get_angle()),
-                    OscType::Float(blob.get_width()),
-                    OscType::Float(blob.get_height()),
-                    OscType::Float(blob.get_area()),
-                    OscType::Float(blob.get_x_velocity()),
-                    OscType::Float(blob.get_y_velocity()),
-                    OscType::Float(blob.get_rotation_speed()),
-                    OscType::Float(blob.get_acceleration()),
-                    OscType::Float(blob.get_rotation_acceleration())
-                ]
-            }));
-        }
-        
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(blob_ids.into_iter()).collect()
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
-        });
-    
-        OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-            content: vec![
-                source_message,
-                alive_message
-            ].into_iter()
-            .chain(set_messages.into_iter())
-            .chain(iter::once(frame_message))
-            .collect()
-        }
+        encode_full_bundle("/tuio/2Dblb", source_name, frame_id, blob_collection)
+    }
+
+    fn encode_object_25d_bundle<'a, I>(object_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Object25D> {
+        encode_full_bundle("/tuio/25Dobj", source_name, frame_id, object_collection)
+    }
+
+    fn encode_cursor_25d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Cursor25D> {
+        encode_full_bundle("/tuio/25Dcur", source_name, frame_id, cursor_collection)
+    }
+
+    fn encode_blob_25d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Blob25D> {
+        encode_full_bundle("/tuio/25Dblb", source_name, frame_id, blob_collection)
+    }
+
+    fn encode_object_3d_bundle<'a, I>(object_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Object3D> {
+        encode_full_bundle("/tuio/3Dobj", source_name, frame_id, object_collection)
+    }
+
+    fn encode_cursor_3d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Cursor3D> {
+        encode_full_bundle("/tuio/3Dcur", source_name, frame_id, cursor_collection)
+    }
+
+    fn encode_blob_3d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Blob3D> {
+        encode_full_bundle("/tuio/3Dblb", source_name, frame_id, blob_collection)
+    }
+
+    fn encode_custom_bundle<'a, I>(custom_collection: I, format: &str, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a CustomProfile> {
+        encode_full_bundle(&format!("/tuio/_{}", format), source_name, frame_id, custom_collection)
     }
 }
 
@@ -204,13 +353,28 @@ pub enum Set {
     Cursor(Vec<Cursor>),
     Object(Vec<Object>),
     Blob(Vec<Blob>),
+    Cursor25D(Vec<Cursor25D>),
+    Object25D(Vec<Object25D>),
+    Blob25D(Vec<Blob25D>),
+    Cursor3D(Vec<Cursor3D>),
+    Object3D(Vec<Object3D>),
+    Blob3D(Vec<Blob3D>),
+    Custom(Vec<CustomProfile>),
 }
 
-#[derive(Default)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TuioBundleType {
     Cursor,
     Object,
     Blob,
+    Cursor25D,
+    Object25D,
+    Blob25D,
+    Cursor3D,
+    Object3D,
+    Blob3D,
+    /// A custom profile with its format string
+    Custom(String),
     #[default]
     Unknown
 }
@@ -282,6 +446,123 @@ fn try_unwrap_blob_args(args: &[OscType]) -> Result<Blob, u8> {
     })
 }
 
+fn try_unwrap_object_25d_args(args: &[OscType]) -> Result<Object25D, u8> {
+    Ok(Object25D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        class_id: args[2].clone().int().ok_or(2)?,
+        position: Position3D {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?, z: args[5].clone().float().ok_or(5)?},
+        angle: args[6].clone().float().ok_or(6)?,
+        velocity: Velocity3D {x: args[7].clone().float().ok_or(7)?, y: args[8].clone().float().ok_or(8)?, z: args[9].clone().float().ok_or(9)?},
+        rotation_speed: args[10].clone().float().ok_or(10)?,
+        acceleration: args[11].clone().float().ok_or(11)?,
+        rotation_acceleration: args[12].clone().float().ok_or(12)?,
+    })
+}
+
+fn try_unwrap_cursor_25d_args(args: &[OscType]) -> Result<Cursor25D, u8> {
+    Ok(Cursor25D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        velocity: Velocity3D {x: args[5].clone().float().ok_or(5)?, y: args[6].clone().float().ok_or(6)?, z: args[7].clone().float().ok_or(7)?},
+        acceleration: args[8].clone().float().ok_or(8)?,
+    })
+}
+
+fn try_unwrap_blob_25d_args(args: &[OscType]) -> Result<Blob25D, u8> {
+    Ok(Blob25D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        width: args[6].clone().float().ok_or(6)?,
+        height: args[7].clone().float().ok_or(7)?,
+        area: args[8].clone().float().ok_or(8)?,
+        velocity: Velocity3D {x: args[9].clone().float().ok_or(9)?, y: args[10].clone().float().ok_or(10)?, z: args[11].clone().float().ok_or(11)?},
+        rotation_speed: args[12].clone().float().ok_or(12)?,
+        acceleration: args[13].clone().float().ok_or(13)?,
+        rotation_acceleration: args[14].clone().float().ok_or(14)?,
+    })
+}
+
+fn try_unwrap_object_3d_args(args: &[OscType]) -> Result<Object3D, u8> {
+    Ok(Object3D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        class_id: args[2].clone().int().ok_or(2)?,
+        position: Position3D {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?, z: args[5].clone().float().ok_or(5)?},
+        angle: Angle3D {a: args[6].clone().float().ok_or(6)?, b: args[7].clone().float().ok_or(7)?, c: args[8].clone().float().ok_or(8)?},
+        velocity: Velocity3D {x: args[9].clone().float().ok_or(9)?, y: args[10].clone().float().ok_or(10)?, z: args[11].clone().float().ok_or(11)?},
+        rotation_speed: Angle3D {a: args[12].clone().float().ok_or(12)?, b: args[13].clone().float().ok_or(13)?, c: args[14].clone().float().ok_or(14)?},
+        acceleration: args[15].clone().float().ok_or(15)?,
+        rotation_acceleration: args[16].clone().float().ok_or(16)?,
+    })
+}
+
+fn try_unwrap_cursor_3d_args(args: &[OscType]) -> Result<Cursor3D, u8> {
+    Ok(Cursor3D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        velocity: Velocity3D {x: args[5].clone().float().ok_or(5)?, y: args[6].clone().float().ok_or(6)?, z: args[7].clone().float().ok_or(7)?},
+        acceleration: args[8].clone().float().ok_or(8)?,
+    })
+}
+
+fn try_unwrap_blob_3d_args(args: &[OscType]) -> Result<Blob3D, u8> {
+    Ok(Blob3D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        angle: Angle3D {a: args[5].clone().float().ok_or(5)?, b: args[6].clone().float().ok_or(6)?, c: args[7].clone().float().ok_or(7)?},
+        width: args[8].clone().float().ok_or(8)?,
+        height: args[9].clone().float().ok_or(9)?,
+        depth: args[10].clone().float().ok_or(10)?,
+        volume: args[11].clone().float().ok_or(11)?,
+        velocity: Velocity3D {x: args[12].clone().float().ok_or(12)?, y: args[13].clone().float().ok_or(13)?, z: args[14].clone().float().ok_or(14)?},
+        rotation_speed: Angle3D {a: args[15].clone().float().ok_or(15)?, b: args[16].clone().float().ok_or(16)?, c: args[17].clone().float().ok_or(17)?},
+        acceleration: args[18].clone().float().ok_or(18)?,
+        rotation_acceleration: args[19].clone().float().ok_or(19)?,
+    })
+}
+
+fn try_unwrap_custom_args(format: &str, args: &[OscType]) -> Result<CustomProfile, u8> {
+    let mut values = Vec::with_capacity(args.len() - 2);
+
+    for (index, attribute) in format.chars().enumerate().skip(1) {
+        let arg = args[index + 1].clone();
+        let index = (index + 1) as u8;
+
+        values.push(if is_int_attribute(attribute) {
+            CustomValue::Int(arg.int().ok_or(index)?)
+        } else {
+            CustomValue::Float(arg.float().ok_or(index)?)
+        });
+    }
+
+    Ok(CustomProfile {
+        session_id: args[1].clone().int().ok_or(1)?,
+        format: format.into(),
+        values,
+    })
+}
+
+/// Decodes a "set" message and pushes the result into a set collection
+///
+/// # Arguments
+/// * `message` - the "set" [OscMessage]
+/// * `set` - the collection to push into
+/// * `arg_count` - the expected number of arguments, including the "set" command
+/// * `try_unwrap` - a function converting the arguments, returning the index of the first wrong argument on failure
+fn decode_set<T>(message: &OscMessage, set: &mut Vec<T>, arg_count: usize, try_unwrap: impl FnOnce(&[OscType]) -> Result<T, u8>) -> Result<(), TuioError> {
+    if message.args.len() != arg_count {
+        return Err(TuioError::MissingArguments(message.clone()));
+    }
+
+    match try_unwrap(&message.args) {
+        Ok(params) => {
+            set.push(params);
+            Ok(())
+        },
+        Err(index) => Err(TuioError::WrongArgumentType(message.clone(), index)),
+    }
+}
+
 impl DecodeOsc<OscBundle> for OscDecoder {
     fn decode_bundle(bundle: OscBundle) -> Result<TuioBundle, TuioError> {
         let mut decoded_bundle = TuioBundle::default();
@@ -296,6 +577,13 @@ impl DecodeOsc<OscBundle> for OscDecoder {
                                     "/tuio/2Dobj" => TuioBundleType::Object,
                                     "/tuio/2Dcur" => TuioBundleType::Cursor,
                                     "/tuio/2Dblb" => TuioBundleType::Blob,
+                                    "/tuio/25Dobj" => TuioBundleType::Object25D,
+                                    "/tuio/25Dcur" => TuioBundleType::Cursor25D,
+                                    "/tuio/25Dblb" => TuioBundleType::Blob25D,
+                                    "/tuio/3Dobj" => TuioBundleType::Object3D,
+                                    "/tuio/3Dcur" => TuioBundleType::Cursor3D,
+                                    "/tuio/3Dblb" => TuioBundleType::Blob3D,
+                                    address if address.starts_with("/tuio/_s") && address.is_ascii() => TuioBundleType::Custom(address["/tuio/_".len()..].into()),
                                     _ => return Err(TuioError::UnknownAddress(message.clone()))
                                 };
 
@@ -305,49 +593,38 @@ impl DecodeOsc<OscBundle> for OscDecoder {
                                 decoded_bundle.alive = message.args.iter().skip(1).filter_map(|e| e.clone().int()).collect();
                             },
                             "set" => {
-                                match decoded_bundle.tuio_type {
-                                    TuioBundleType::Cursor => {
-                                        if let Set::Cursor(set) = decoded_bundle.set.get_or_insert(Set::Cursor(Vec::new())) {
-                                            if message.args.len() != 7 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-        
-                                            match try_unwrap_cursor_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
+                                let set = &mut decoded_bundle.set;
+
+                                match &decoded_bundle.tuio_type {
+                                    TuioBundleType::Cursor => if let Set::Cursor(set) = set.get_or_insert(Set::Cursor(Vec::new())) {
+                                        decode_set(message, set, 7, try_unwrap_cursor_args)?;
                                     },
-                                    TuioBundleType::Object => {
-                                        if let Set::Object(set) = decoded_bundle.set.get_or_insert(Set::Object(Vec::new())) {
-                                            if message.args.len() != 11 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-                                            
-                                            match try_unwrap_object_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
+                                    TuioBundleType::Object => if let Set::Object(set) = set.get_or_insert(Set::Object(Vec::new())) {
+                                        decode_set(message, set, 11, try_unwrap_object_args)?;
                                     },
-                                    TuioBundleType::Blob => {
-                                        if let Set::Blob(set) = decoded_bundle.set.get_or_insert(Set::Blob(Vec::new())) {
-                                            if message.args.len() != 13 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-        
-                                            match try_unwrap_blob_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
-
+                                    TuioBundleType::Blob => if let Set::Blob(set) = set.get_or_insert(Set::Blob(Vec::new())) {
+                                        decode_set(message, set, 13, try_unwrap_blob_args)?;
+                                    },
+                                    TuioBundleType::Cursor25D => if let Set::Cursor25D(set) = set.get_or_insert(Set::Cursor25D(Vec::new())) {
+                                        decode_set(message, set, 9, try_unwrap_cursor_25d_args)?;
+                                    },
+                                    TuioBundleType::Object25D => if let Set::Object25D(set) = set.get_or_insert(Set::Object25D(Vec::new())) {
+                                        decode_set(message, set, 13, try_unwrap_object_25d_args)?;
+                                    },
+                                    TuioBundleType::Blob25D => if let Set::Blob25D(set) = set.get_or_insert(Set::Blob25D(Vec::new())) {
+                                        decode_set(message, set, 15, try_unwrap_blob_25d_args)?;
+                                    },
+                                    TuioBundleType::Cursor3D => if let Set::Cursor3D(set) = set.get_or_insert(Set::Cursor3D(Vec::new())) {
+                                        decode_set(message, set, 9, try_unwrap_cursor_3d_args)?;
+                                    },
+                                    TuioBundleType::Object3D => if let Set::Object3D(set) = set.get_or_insert(Set::Object3D(Vec::new())) {
+                                        decode_set(message, set, 17, try_unwrap_object_3d_args)?;
+                                    },
+                                    TuioBundleType::Blob3D => if let Set::Blob3D(set) = set.get_or_insert(Set::Blob3D(Vec::new())) {
+                                        decode_set(message, set, 20, try_unwrap_blob_3d_args)?;
+                                    },
+                                    TuioBundleType::Custom(format) => if let Set::Custom(set) = set.get_or_insert(Set::Custom(Vec::new())) {
+                                        decode_set(message, set, format.len() + 1, |args| try_unwrap_custom_args(format, args))?;
                                     },
                                     TuioBundleType::Unknown => return Err(TuioError::IncompleteBundle(bundle)),
                                 }
@@ -375,7 +652,7 @@ impl DecodeOsc<OscBundle> for OscDecoder {
 
 #[cfg(test)]
 mod tests {
-    use crate::{cursor::{Cursor, Position}, object::Object, blob::Blob, osc_encode_decode::{OscEncoder, EncodeOsc}};
+    use crate::{cursor::{Cursor, Position, Position3D, Angle3D}, object::Object, blob::Blob, osc_encode_decode::{OscEncoder, EncodeOsc}};
 
     use super::*;
 
@@ -424,4 +701,125 @@ mod tests {
             Err(err) => {println!("{err}"); panic!()},
         }
     }
+
+    #[test]
+    fn encoding_decoding_25d() {
+        let source = "test".to_string();
+
+        let cursors = vec![Cursor25D::new(0, Position3D {x: 0., y: 0., z: 0.}), Cursor25D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.2}).with_motion(Velocity3D {x: 0.1, y: 0.2, z: 0.3}, 0.4)];
+        let objects = vec![Object25D::new(0, 0, Position3D {x: 0., y: 0., z: 0.}, 0.), Object25D::new(1, 1, Position3D {x: 0.5, y: 0.5, z: 0.2}, 1.)];
+        let blobs = vec![Blob25D::new(0, Position3D {x: 0., y: 0., z: 0.}, 0., 0.3, 0.3, 0.09), Blob25D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.2}, 1., 0.5, 0.5, 0.25)];
+
+        let cursor_bundle = OscEncoder::encode_cursor_25d_bundle(&cursors, source.clone(), 0);
+        let object_bundle = OscEncoder::encode_object_25d_bundle(&objects, source.clone(), 0);
+        let blob_bundle = OscEncoder::encode_blob_25d_bundle(&blobs, source, 0);
+
+        match OscDecoder::decode_bundle(cursor_bundle) {
+            Ok(TuioBundle {set: Some(Set::Cursor25D(decoded_cursors)), ..}) => {
+                assert_eq!(decoded_cursors.len(), 2);
+                assert_eq!(cursors[0], decoded_cursors[0]);
+                assert_eq!(cursors[1], decoded_cursors[1]);
+            },
+            Ok(_) => panic!("expected a 2.5D cursor set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(object_bundle) {
+            Ok(TuioBundle {set: Some(Set::Object25D(decoded_objects)), ..}) => {
+                assert_eq!(decoded_objects.len(), 2);
+                assert_eq!(objects[0], decoded_objects[0]);
+                assert_eq!(objects[1], decoded_objects[1]);
+            },
+            Ok(_) => panic!("expected a 2.5D object set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(blob_bundle) {
+            Ok(TuioBundle {set: Some(Set::Blob25D(decoded_blobs)), ..}) => {
+                assert_eq!(decoded_blobs.len(), 2);
+                assert_eq!(blobs[0], decoded_blobs[0]);
+                assert_eq!(blobs[1], decoded_blobs[1]);
+            },
+            Ok(_) => panic!("expected a 2.5D blob set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+    }
+
+    #[test]
+    fn encoding_decoding_3d() {
+        let source = "test".to_string();
+
+        let cursors = vec![Cursor3D::new(0, Position3D {x: 0., y: 0., z: 0.}), Cursor3D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.5}).with_motion(Velocity3D {x: 0.1, y: 0.2, z: 0.3}, 0.4)];
+        let objects = vec![
+            Object3D::new(0, 0, Position3D {x: 0., y: 0., z: 0.}, Angle3D::default()),
+            Object3D::new(1, 1, Position3D {x: 0.5, y: 0.5, z: 0.5}, Angle3D {a: 0.1, b: 0.2, c: 0.3}).with_motion(Velocity3D {x: 0.1, y: 0.2, z: 0.3}, Angle3D {a: 0.4, b: 0.5, c: 0.6}, 0.7, 0.8)
+        ];
+        let blobs = vec![
+            Blob3D::new(0, Position3D {x: 0., y: 0., z: 0.}, Angle3D::default(), 0.3, 0.3, 0.3, 0.027),
+            Blob3D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.5}, Angle3D {a: 0.1, b: 0.2, c: 0.3}, 0.5, 0.5, 0.5, 0.125).with_motion(Velocity3D {x: 0.1, y: 0.2, z: 0.3}, Angle3D {a: 0.4, b: 0.5, c: 0.6}, 0.7, 0.8)
+        ];
+
+        let cursor_bundle = OscEncoder::encode_cursor_3d_bundle(&cursors, source.clone(), 0);
+        let object_bundle = OscEncoder::encode_object_3d_bundle(&objects, source.clone(), 0);
+        let blob_bundle = OscEncoder::encode_blob_3d_bundle(&blobs, source, 0);
+
+        match OscDecoder::decode_bundle(cursor_bundle) {
+            Ok(TuioBundle {set: Some(Set::Cursor3D(decoded_cursors)), ..}) => {
+                assert_eq!(decoded_cursors.len(), 2);
+                assert_eq!(cursors[0], decoded_cursors[0]);
+                assert_eq!(cursors[1], decoded_cursors[1]);
+            },
+            Ok(_) => panic!("expected a 3D cursor set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(object_bundle) {
+            Ok(TuioBundle {set: Some(Set::Object3D(decoded_objects)), ..}) => {
+                assert_eq!(decoded_objects.len(), 2);
+                assert_eq!(objects[0], decoded_objects[0]);
+                assert_eq!(objects[1], decoded_objects[1]);
+            },
+            Ok(_) => panic!("expected a 3D object set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(blob_bundle) {
+            Ok(TuioBundle {set: Some(Set::Blob3D(decoded_blobs)), ..}) => {
+                assert_eq!(decoded_blobs.len(), 2);
+                assert_eq!(blobs[0], decoded_blobs[0]);
+                assert_eq!(blobs[1], decoded_blobs[1]);
+            },
+            Ok(_) => panic!("expected a 3D blob set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+    }
+
+    #[test]
+    fn encoding_decoding_custom() {
+        let customs = vec![
+            CustomProfile::new(0, "sixyP", vec![CustomValue::Int(1), CustomValue::Float(0.), CustomValue::Float(0.), CustomValue::Float(0.5)]),
+            CustomProfile::new(1, "sixyP", vec![CustomValue::Int(2), CustomValue::Float(0.5), CustomValue::Float(0.5), CustomValue::Float(1.)])
+        ];
+
+        let custom_bundle = OscEncoder::encode_custom_bundle(&customs, "sixyP", "test".into(), 0);
+
+        match OscDecoder::decode_bundle(custom_bundle) {
+            Ok(TuioBundle {tuio_type: TuioBundleType::Custom(format), set: Some(Set::Custom(decoded_customs)), ..}) => {
+                assert_eq!(format, "sixyP");
+                assert_eq!(decoded_customs.len(), 2);
+                assert_eq!(customs[0], decoded_customs[0]);
+                assert_eq!(customs[1], decoded_customs[1]);
+            },
+            Ok(_) => panic!("expected a custom set"),
+            Err(err) => {println!("{err}"); panic!()},
+        }
+    }
+
+    #[test]
+    fn decoding_custom_wrong_type() {
+        let customs = vec![CustomProfile::new(0, "sixy", vec![CustomValue::Float(1.), CustomValue::Float(0.), CustomValue::Float(0.)])];
+        let custom_bundle = OscEncoder::encode_custom_bundle(&customs, "sixy", "test".into(), 0);
+
+        assert!(matches!(OscDecoder::decode_bundle(custom_bundle), Err(TuioError::WrongArgumentType(_, 2))));
+    }
 }
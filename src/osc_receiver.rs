@@ -1,13 +1,20 @@
 use std::{
-    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket, TcpStream, Shutdown},
     sync::{
-        Arc,
-    }, error::Error,
+        Arc, Mutex, atomic::{AtomicBool, Ordering}, mpsc::{self, Sender, Receiver},
+    }, error::Error, io::{self, BufReader}, time::Duration,
 };
 
+#[cfg(unix)]
+use std::{os::unix::net::{UnixDatagram, UnixStream}, path::{Path, PathBuf}};
+
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
 use rosc::{OscPacket};
+use socket2::{Socket, Domain, Type, Protocol};
 
-use crate::errors::OscReceiverError;
+use crate::{errors::OscReceiverError, framing::Framing, server::ChannelSender};
 
 /// Base trait to implement receiving OSC over various transport methods
 pub trait OscReceiver<P, E: Error> {
@@ -24,8 +31,50 @@ pub trait OscReceiver<P, E: Error> {
     fn recv(&self) -> Result<P, E>;
 }
 
+/// A boxed [Future] which can be sent across threads, keeping the async traits object safe
+#[cfg(feature = "async")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Base trait to implement receiving OSC asynchronously over various transport methods
+#[cfg(feature = "async")]
+pub trait AsyncOscReceiver<P, E: Error> {
+    /// Returns a true if the connection is established
+    fn is_connected(&self) -> bool;
+
+    /// Establishes connection
+    fn connect(&self) -> BoxFuture<'_, Result<(), std::io::Error>>;
+
+    /// Stops connection
+    fn disconnect(&self);
+
+    /// Receives a single OSC packet.
+    fn recv(&self) -> BoxFuture<'_, Result<P, E>>;
+}
+
+/// The read timeout after which a blocked datagram receiver checks whether it has been disconnected
+pub(crate) const DISCONNECT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Receives a datagram, retrying on read timeouts until one arrives or `stopped` is set
+///
+/// # Arguments
+/// * `stopped` - the flag set by [OscReceiver::disconnect]
+/// * `recv` - receives a datagram into the caller's buffer, returning its size
+fn recv_datagram(stopped: &AtomicBool, mut recv: impl FnMut() -> io::Result<usize>) -> Result<usize, OscReceiverError> {
+    loop {
+        if stopped.load(Ordering::SeqCst) {
+            return Err(OscReceiverError::Receive(io::ErrorKind::NotConnected.into()));
+        }
+
+        match recv() {
+            Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => continue,
+            result => return result.map_err(OscReceiverError::Receive),
+        }
+    }
+}
+
 pub struct UdpReceiver {
-    socket: Arc<UdpSocket>
+    socket: Arc<UdpSocket>,
+    stopped: AtomicBool,
 }
 
 impl UdpReceiver {
@@ -36,35 +85,694 @@ impl UdpReceiver {
     
     /// Creates an [UdpReceiver] binded to the provided port
     pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
+        Self::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
+
+    /// Creates an [UdpReceiver] binded to the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - a socket address such as `0.0.0.0:3333` for all IPv4 interfaces or `[::]:3333` for IPv6
+    pub fn from_socket_addr(address: SocketAddr) -> Result<Self, std::io::Error> {
+        Self::from_socket(UdpSocket::bind(address)?)
+    }
+
+    /// Creates an [UdpReceiver] binded to the provided socket address with [SocketOptions]
+    ///
+    /// # Arguments
+    /// * `address` - the socket address to bind
+    /// * `options` - the [SocketOptions] applied before binding
+    pub fn with_options(address: SocketAddr, options: SocketOptions) -> Result<Self, std::io::Error> {
+        let socket = Socket::new(Domain::for_address(address), Type::DGRAM, Some(Protocol::UDP))?;
+        socket.set_reuse_address(options.reuse_address)?;
+
+        #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+        socket.set_reuse_port(options.reuse_port)?;
+
+        if let (SocketAddr::V6(_), Some(only_v6)) = (address, options.only_v6) {
+            socket.set_only_v6(only_v6)?;
+        }
+
+        socket.bind(&address.into())?;
+        Self::from_socket(socket.into())
+    }
+
+    fn from_socket(socket: UdpSocket) -> Result<Self, std::io::Error> {
+        socket.set_read_timeout(Some(DISCONNECT_POLL_INTERVAL))?;
+
         Ok(Self {
-            socket: Arc::new(UdpSocket::bind(SocketAddr::new(
-                IpAddr::V4(Ipv4Addr::LOCALHOST),
-                port,
-            ))?)
+            socket: Arc::new(socket),
+            stopped: AtomicBool::new(false),
         })
     }
+
+    /// Joins an IPv4 multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the multicast group's address
+    /// * `interface` - the address of the interface to join on, [Ipv4Addr::UNSPECIFIED] letting the system choose
+    pub fn join_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), std::io::Error> {
+        self.socket.join_multicast_v4(&group, &interface)
+    }
+
+    /// Leaves an IPv4 multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the multicast group's address
+    /// * `interface` - the address of the interface it was joined on
+    pub fn leave_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), std::io::Error> {
+        self.socket.leave_multicast_v4(&group, &interface)
+    }
+
+    /// Joins an IPv6 multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the multicast group's address
+    /// * `interface` - the index of the interface to join on, 0 letting the system choose
+    pub fn join_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), std::io::Error> {
+        self.socket.join_multicast_v6(&group, interface)
+    }
+
+    /// Leaves an IPv6 multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the multicast group's address
+    /// * `interface` - the index of the interface it was joined on
+    pub fn leave_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), std::io::Error> {
+        self.socket.leave_multicast_v6(&group, interface)
+    }
+
+    /// Returns the socket address the [UdpReceiver] is binded to
+    pub fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
+        self.socket.local_addr()
+    }
+}
+
+/// Options applied to a socket before binding it
+///
+/// Reusing an address lets several applications bind the same port, but a unicast datagram is only
+/// delivered to one of them. Broadcast and multicast datagrams are delivered to each of them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SocketOptions {
+    /// Sets SO_REUSEADDR
+    pub reuse_address: bool,
+    /// Sets SO_REUSEPORT, ignored on platforms without it
+    pub reuse_port: bool,
+    /// Sets IPV6_V6ONLY on IPv6 addresses, `Some(false)` making `[::]` also receive IPv4. [None] keeps the system's default
+    pub only_v6: Option<bool>,
 }
 
 pub type RoscReceiver = dyn OscReceiver<OscPacket, OscReceiverError> + Send + Sync;
 
+#[cfg(feature = "async")]
+pub type AsyncRoscReceiver = dyn AsyncOscReceiver<OscPacket, OscReceiverError> + Send + Sync;
+
 impl OscReceiver<OscPacket, OscReceiverError> for UdpReceiver {
     fn connect(&self) -> Result<(), std::io::Error> {
+        self.stopped.store(false, Ordering::SeqCst);
         Ok(())
     }
 
-    fn disconnect(&self) {}
+    /// Stops receiving, a pending [OscReceiver::recv] returning within 100 ms
+    fn disconnect(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
 
-    /// Always returns true because UDP is connectionless
+    /// Returns false once disconnected, UDP being connectionless
     fn is_connected(&self) -> bool {
-        true
+        !self.stopped.load(Ordering::SeqCst)
     }
 
     fn recv(&self) -> Result<OscPacket, OscReceiverError> {
         let mut buf = [0u8; rosc::decoder::MTU];
 
-        let (size, _) = self.socket.recv_from(&mut buf).map_err(OscReceiverError::Receive)?;
+        let size = recv_datagram(&self.stopped, || self.socket.recv(&mut buf))?;
         let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).map_err(OscReceiverError::Decode)?;
 
         Ok(packet)
     }
-}
\ No newline at end of file
+}
+
+/// Receives framed OSC packets from a TCP server
+pub struct TcpReceiver {
+    address: SocketAddr,
+    framing: Framing,
+    stream: Mutex<Option<TcpStream>>,
+    reader: Mutex<Option<BufReader<TcpStream>>>,
+}
+
+impl TcpReceiver {
+    /// Creates a [TcpReceiver] connecting to a TUIO server on [OscReceiver::connect]
+    ///
+    /// # Arguments
+    /// * `address` - the server's socket address
+    /// * `framing` - the [Framing] of the received packets
+    pub fn new(address: SocketAddr, framing: Framing) -> Self {
+        Self {
+            address,
+            framing,
+            stream: Mutex::new(None),
+            reader: Mutex::new(None),
+        }
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for TcpReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        let stream = TcpStream::connect(self.address)?;
+        *self.reader.lock().unwrap() = Some(BufReader::new(stream.try_clone()?));
+        *self.stream.lock().unwrap() = Some(stream);
+        Ok(())
+    }
+
+    /// Shuts the connection down, unblocking a pending [OscReceiver::recv]
+    fn disconnect(&self) {
+        if let Some(stream) = self.stream.lock().unwrap().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.lock().unwrap().is_some()
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut reader = self.reader.lock().unwrap();
+        let Some(stream) = reader.as_mut() else {
+            return Err(OscReceiverError::Receive(io::ErrorKind::NotConnected.into()));
+        };
+
+        let buffer = self.framing.read_packet(stream).map_err(|err| {
+            if err.kind() == io::ErrorKind::UnexpectedEof {
+                self.stream.lock().unwrap().take();
+            }
+
+            OscReceiverError::Receive(err)
+        })?;
+        let (_, packet) = rosc::decoder::decode_udp(&buffer).map_err(OscReceiverError::Decode)?;
+
+        Ok(packet)
+    }
+}
+
+/// Receives OSC packets from a Unix datagram socket
+#[cfg(unix)]
+pub struct UnixDatagramReceiver {
+    socket: UnixDatagram,
+    path: PathBuf,
+    stopped: AtomicBool,
+}
+
+#[cfg(unix)]
+impl UnixDatagramReceiver {
+    /// Creates an [UnixDatagramReceiver] binded to a socket file, removed when the receiver is dropped
+    ///
+    /// # Arguments
+    /// * `path` - the path of the socket file to create
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let socket = UnixDatagram::bind(&path)?;
+        socket.set_read_timeout(Some(DISCONNECT_POLL_INTERVAL))?;
+
+        Ok(Self {socket, path: path.as_ref().into(), stopped: AtomicBool::new(false)})
+    }
+}
+
+#[cfg(unix)]
+impl OscReceiver<OscPacket, OscReceiverError> for UnixDatagramReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        self.stopped.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Stops receiving, a pending [OscReceiver::recv] returning within 100 ms
+    fn disconnect(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns false once disconnected, datagram sockets being connectionless
+    fn is_connected(&self) -> bool {
+        !self.stopped.load(Ordering::SeqCst)
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut buf = [0u8; rosc::decoder::MTU];
+
+        let size = recv_datagram(&self.stopped, || self.socket.recv(&mut buf))?;
+        let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).map_err(OscReceiverError::Decode)?;
+
+        Ok(packet)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for UnixDatagramReceiver {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Receives framed OSC packets from a Unix stream socket
+#[cfg(unix)]
+pub struct UnixStreamReceiver {
+    path: PathBuf,
+    framing: Framing,
+    stream: Mutex<Option<UnixStream>>,
+    reader: Mutex<Option<BufReader<UnixStream>>>,
+}
+
+#[cfg(unix)]
+impl UnixStreamReceiver {
+    /// Creates an [UnixStreamReceiver] connecting to a socket file on [OscReceiver::connect]
+    ///
+    /// # Arguments
+    /// * `path` - the path of the sender's socket file
+    /// * `framing` - the [Framing] of the received packets, OSC 1.1 using [Framing::Slip]
+    pub fn new(path: impl AsRef<Path>, framing: Framing) -> Self {
+        Self {
+            path: path.as_ref().into(),
+            framing,
+            stream: Mutex::new(None),
+            reader: Mutex::new(None),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl OscReceiver<OscPacket, OscReceiverError> for UnixStreamReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        let stream = UnixStream::connect(&self.path)?;
+        *self.reader.lock().unwrap() = Some(BufReader::new(stream.try_clone()?));
+        *self.stream.lock().unwrap() = Some(stream);
+        Ok(())
+    }
+
+    /// Shuts the connection down, unblocking a pending [OscReceiver::recv]
+    fn disconnect(&self) {
+        if let Some(stream) = self.stream.lock().unwrap().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.lock().unwrap().is_some()
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut reader = self.reader.lock().unwrap();
+        let Some(stream) = reader.as_mut() else {
+            return Err(OscReceiverError::Receive(io::ErrorKind::NotConnected.into()));
+        };
+
+        let buffer = self.framing.read_packet(stream).map_err(|err| {
+            if err.kind() == io::ErrorKind::UnexpectedEof {
+                self.stream.lock().unwrap().take();
+            }
+
+            OscReceiverError::Receive(err)
+        })?;
+        let (_, packet) = rosc::decoder::decode_udp(&buffer).map_err(OscReceiverError::Decode)?;
+
+        Ok(packet)
+    }
+}
+
+/// Receives OSC packets from in-process [ChannelSender]s, without any socket
+///
+/// Packets sent while the receiver is disconnected are discarded on [OscReceiver::connect]
+pub struct ChannelReceiver {
+    sender: Sender<Option<OscPacket>>,
+    receiver: Mutex<Receiver<Option<OscPacket>>>,
+    connected: Arc<AtomicBool>,
+}
+
+impl ChannelReceiver {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+            connected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns a new [ChannelSender] feeding this receiver, any number of senders being allowed
+    pub fn sender(&self) -> ChannelSender {
+        ChannelSender::new(self.sender.clone(), Arc::clone(&self.connected))
+    }
+}
+
+impl Default for ChannelReceiver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for ChannelReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        self.receiver.lock().unwrap().try_iter().for_each(drop);
+        self.connected.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Stops the connection, unblocking a pending [OscReceiver::recv]
+    fn disconnect(&self) {
+        if self.connected.swap(false, Ordering::SeqCst) {
+            let _ = self.sender.send(None);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        match self.receiver.lock().unwrap().recv() {
+            Ok(Some(packet)) => Ok(packet),
+            _ => Err(OscReceiverError::Receive(io::ErrorKind::NotConnected.into())),
+        }
+    }
+}
+
+/// Receives OSC packets from an UDP socket registered in a tokio runtime
+#[cfg(feature = "async")]
+pub struct AsyncUdpReceiver {
+    socket: tokio::net::UdpSocket,
+}
+
+#[cfg(feature = "async")]
+impl AsyncUdpReceiver {
+    /// Creates an [AsyncUdpReceiver] binded to the provided port on localhost
+    ///
+    /// Must be called from within a tokio runtime
+    /// # Arguments
+    /// * `port` - the listening port
+    pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
+        Self::from_udp_receiver(UdpReceiver::from_port(port)?)
+    }
+
+    /// Creates an [AsyncUdpReceiver] from an [UdpReceiver], keeping its socket options and multicast memberships
+    ///
+    /// Must be called from within a tokio runtime
+    /// # Arguments
+    /// * `receiver` - the [UdpReceiver] to convert
+    pub fn from_udp_receiver(receiver: UdpReceiver) -> Result<Self, std::io::Error> {
+        let socket = receiver.socket.try_clone()?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {socket: tokio::net::UdpSocket::from_std(socket)?})
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
+        self.socket.local_addr()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncOscReceiver<OscPacket, OscReceiverError> for AsyncUdpReceiver {
+    fn connect(&self) -> BoxFuture<'_, Result<(), std::io::Error>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn disconnect(&self) {}
+
+    /// Always returns true because UDP is connectionless
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    fn recv(&self) -> BoxFuture<'_, Result<OscPacket, OscReceiverError>> {
+        Box::pin(async {
+            let mut buf = [0u8; rosc::decoder::MTU];
+
+            let (size, _) = self.socket.recv_from(&mut buf).await.map_err(OscReceiverError::Receive)?;
+            let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).map_err(OscReceiverError::Decode)?;
+
+            Ok(packet)
+        })
+    }
+}
+
+/// Receives OSC packets from the binary messages of a WebSocket server
+#[cfg(feature = "websocket")]
+pub struct WebSocketReceiver {
+    url: String,
+    stream: Mutex<Option<TcpStream>>,
+    websocket: Mutex<Option<tungstenite::WebSocket<TcpStream>>>,
+}
+
+#[cfg(feature = "websocket")]
+impl WebSocketReceiver {
+    /// Creates a [WebSocketReceiver] connecting to a WebSocket server on [OscReceiver::connect]
+    ///
+    /// # Arguments
+    /// * `url` - the server's URL, such as `ws://localhost:3333`. Secure `wss://` URLs are not supported
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.into(),
+            stream: Mutex::new(None),
+            websocket: Mutex::new(None),
+        }
+    }
+}
+
+#[cfg(feature = "websocket")]
+fn websocket_error(err: tungstenite::Error) -> io::Error {
+    match err {
+        tungstenite::Error::Io(err) => err,
+        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => io::ErrorKind::UnexpectedEof.into(),
+        err => io::Error::other(err),
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl OscReceiver<OscPacket, OscReceiverError> for WebSocketReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        use tungstenite::client::IntoClientRequest;
+
+        let request = self.url.as_str().into_client_request().map_err(websocket_error)?;
+        let host = request.uri().host().unwrap_or_default().trim_matches(|c| c == '[' || c == ']').to_string();
+        let stream = TcpStream::connect((host.as_str(), request.uri().port_u16().unwrap_or(80)))?;
+        let (websocket, _) = tungstenite::client(request, stream.try_clone()?).map_err(|err| match err {
+            tungstenite::HandshakeError::Failure(err) => websocket_error(err),
+            tungstenite::HandshakeError::Interrupted(_) => io::ErrorKind::WouldBlock.into(),
+        })?;
+
+        *self.websocket.lock().unwrap() = Some(websocket);
+        *self.stream.lock().unwrap() = Some(stream);
+        Ok(())
+    }
+
+    /// Shuts the connection down, unblocking a pending [OscReceiver::recv]
+    fn disconnect(&self) {
+        if let Some(stream) = self.stream.lock().unwrap().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.lock().unwrap().is_some()
+    }
+
+    /// Receives the next binary message, skipping text and control messages
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut websocket = self.websocket.lock().unwrap();
+        let Some(websocket) = websocket.as_mut() else {
+            return Err(OscReceiverError::Receive(io::ErrorKind::NotConnected.into()));
+        };
+
+        loop {
+            match websocket.read() {
+                Ok(tungstenite::Message::Binary(buffer)) => {
+                    let (_, packet) = rosc::decoder::decode_udp(&buffer).map_err(OscReceiverError::Decode)?;
+                    return Ok(packet);
+                },
+                Ok(_) => continue,
+                Err(err) => {
+                    let err = websocket_error(err);
+
+                    if err.kind() == io::ErrorKind::UnexpectedEof {
+                        self.stream.lock().unwrap().take();
+                    }
+
+                    return Err(OscReceiverError::Receive(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::{SocketAddr, SocketAddrV4, IpAddr, Ipv4Addr}, thread, time::Duration};
+
+    use rosc::{OscPacket, OscMessage, OscType};
+
+    use crate::{errors::{OscReceiverError, OscSenderError}, framing::Framing, server::{TcpSender, UdpSender, MulticastOptions, SendOsc}, osc_receiver::{OscReceiver, ChannelReceiver, TcpReceiver, UdpReceiver, SocketOptions}};
+
+    fn packet() -> OscPacket {
+        OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::String("fseq".into()), OscType::Int(1)]})
+    }
+
+    #[test]
+    fn channel_send_receive() {
+        let receiver = ChannelReceiver::new();
+        let sender = receiver.sender();
+        assert!(!sender.is_connected());
+
+        sender.send_osc_packet(&packet()).unwrap();
+        receiver.connect().unwrap();
+        assert!(sender.is_connected());
+
+        sender.send_osc_packet(&packet()).unwrap();
+        assert_eq!(receiver.recv().unwrap(), packet());
+
+        receiver.disconnect();
+        assert!(matches!(receiver.recv(), Err(OscReceiverError::Receive(_))));
+
+        drop(receiver);
+        assert!(matches!(sender.send_osc_packet(&packet()), Err(OscSenderError::Send(_))));
+    }
+
+    #[test]
+    fn udp_any_interface() {
+        let receiver = UdpReceiver::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)).unwrap();
+        let target = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), receiver.local_addr().unwrap().port());
+        let packet = packet();
+
+        let socket = std::net::UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        socket.send_to(&rosc::encoder::encode(&packet).unwrap(), target).unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet);
+    }
+
+    #[test]
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn udp_reuse_port() {
+        let options = SocketOptions {reuse_address: true, reuse_port: true, only_v6: None};
+        let receiver = UdpReceiver::with_options(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0), options).unwrap();
+        let address = receiver.local_addr().unwrap();
+
+        assert!(UdpReceiver::with_options(address, options).is_ok());
+        assert!(UdpReceiver::from_socket_addr(address).is_err());
+    }
+
+    #[test]
+    fn udp_multicast() {
+        let group = Ipv4Addr::new(239, 255, 33, 33);
+        let options = SocketOptions {reuse_address: true, reuse_port: true, only_v6: None};
+        let receiver = UdpReceiver::with_options(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0), options).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+
+        receiver.join_multicast_v4(group, Ipv4Addr::LOCALHOST).unwrap();
+
+        let sender = UdpSender::multicast(SocketAddr::new(IpAddr::V4(group), port), MulticastOptions {interface_v4: Ipv4Addr::LOCALHOST, ..Default::default()}).unwrap();
+        sender.send_osc_packet(&packet()).unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet());
+        receiver.leave_multicast_v4(group, Ipv4Addr::LOCALHOST).unwrap();
+
+        assert!(UdpSender::multicast(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port), MulticastOptions::default()).is_err());
+    }
+
+    #[test]
+    fn udp_broadcast() {
+        let receiver = UdpReceiver::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+
+        let sender = UdpSender::broadcast(SocketAddrV4::new(Ipv4Addr::new(127, 255, 255, 255), port)).unwrap();
+        sender.send_osc_packet(&packet()).unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn unix_send_receive() {
+        use crate::{server::{UnixDatagramSender, UnixStreamSender}, osc_receiver::{UnixDatagramReceiver, UnixStreamReceiver}};
+
+        let datagram_path = std::env::temp_dir().join(format!("tuio-rs-datagram-{}.sock", std::process::id()));
+        let receiver = UnixDatagramReceiver::new(&datagram_path).unwrap();
+        UnixDatagramSender::new(&datagram_path).unwrap().send_osc_packet(&packet()).unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet());
+        drop(receiver);
+        assert!(!datagram_path.exists());
+
+        let stream_path = std::env::temp_dir().join(format!("tuio-rs-stream-{}.sock", std::process::id()));
+        let sender = UnixStreamSender::new(&stream_path, Framing::Slip).unwrap();
+        let receiver = UnixStreamReceiver::new(&stream_path, Framing::Slip);
+        receiver.connect().unwrap();
+
+        while sender.client_count() == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        sender.send_osc_packet(&packet()).unwrap();
+        assert_eq!(receiver.recv().unwrap(), packet());
+
+        drop(sender);
+        assert!(!stream_path.exists());
+    }
+
+    #[test]
+    fn tcp_send_receive() {
+        let packet = packet();
+
+        for framing in [Framing::LengthPrefix, Framing::Slip] {
+            let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0), framing).unwrap();
+            let receivers = [TcpReceiver::new(sender.local_addr(), framing), TcpReceiver::new(sender.local_addr(), framing)];
+
+            for receiver in &receivers {
+                receiver.connect().unwrap();
+            }
+
+            while sender.client_count() < receivers.len() {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            sender.send_osc_packet(&packet).unwrap();
+
+            for receiver in &receivers {
+                assert_eq!(receiver.recv().unwrap(), packet);
+            }
+
+            receivers[0].disconnect();
+            assert!(!receivers[0].is_connected());
+
+            // The disconnected client is removed once writing to it fails
+            while sender.client_count() > 1 {
+                sender.send_osc_packet(&packet).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            assert_eq!(receivers[1].recv().unwrap(), packet);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "websocket"))]
+mod websocket_tests {
+    use std::{net::{SocketAddr, IpAddr, Ipv4Addr}, thread, time::Duration};
+
+    use rosc::{OscPacket, OscMessage, OscType};
+
+    use crate::{server::{WebSocketSender, SendOsc}, osc_receiver::{OscReceiver, WebSocketReceiver}};
+
+    #[test]
+    fn websocket_send_receive() {
+        let packet = OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::String("fseq".into()), OscType::Int(1)]});
+        let sender = WebSocketSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let receiver = WebSocketReceiver::new(&format!("ws://{}", sender.local_addr()));
+
+        receiver.connect().unwrap();
+
+        while sender.client_count() == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        sender.send_osc_packet(&packet).unwrap();
+        assert_eq!(receiver.recv().unwrap(), packet);
+
+        receiver.disconnect();
+        assert!(!receiver.is_connected());
+    }
+}
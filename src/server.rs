@@ -1,11 +1,15 @@
-use std::{net::{SocketAddr, UdpSocket, Ipv4Addr, Ipv6Addr, IpAddr}, sync::atomic::{AtomicI32, Ordering}, time::{SystemTime, Instant, Duration}, error::Error, num::Wrapping};
-use rosc::{OscPacket, OscMessage, OscBundle, OscTime, OscError};
+use std::{net::{SocketAddr, SocketAddrV4, UdpSocket, TcpListener, TcpStream, Ipv4Addr, Ipv6Addr, IpAddr}, sync::{atomic::{AtomicI32, AtomicBool, Ordering}, mpsc::Sender, Arc, Mutex}, time::{SystemTime, Instant, Duration}, error::Error, num::Wrapping, collections::HashSet, io::Write, thread::{self, JoinHandle}};
+use rosc::{OscPacket, OscMessage, OscBundle, OscTime};
 use rosc::encoder;
 use rosc::OscType;
 use local_ip_address::local_ip;
+#[cfg(unix)]
+use std::{os::unix::net::{UnixDatagram, UnixListener, UnixStream}, path::{Path, PathBuf}};
 use indexmap::{IndexMap};
 
-use crate::{cursor::{Position}, osc_encode_decode::{EncodeOsc, OscEncoder}, Object, Cursor, Blob}; 
+#[cfg(feature = "async")]
+use crate::osc_receiver::BoxFuture;
+use crate::{cursor::{Position, Position3D, Angle3D}, custom_profile, errors::{CustomProfileError, OscSenderError}, framing::Framing, osc_encode_decode::{self, EncodeOsc, EncodeSet, OscEncoder}, Object, Cursor, Blob, Object25D, Cursor25D, Blob25D, Object3D, Cursor3D, Blob3D, CustomProfile, CustomValue}; 
 
 /// Base trait to implement sending OSC over various transport methods
 pub trait SendOsc<P, E> where E: Error {
@@ -23,31 +27,169 @@ pub trait SendOsc<P, E> where E: Error {
     fn is_local(&self) -> bool;
 }
 
+/// Base trait to implement sending OSC asynchronously over various transport methods
+#[cfg(feature = "async")]
+pub trait AsyncSendOsc<P, E> where E: Error {
+    /// Sends an OSC packet.
+    /// Returns an [Error] if packet's encoding fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an OSC packet
+    fn send_osc_packet<'a>(&'a self, packet: &'a P) -> BoxFuture<'a, Result<(), E>>;
+
+    /// Returns a true if the connection is established
+    fn is_connected(&self) -> bool;
+
+    /// Returns true if the target is a loopback address
+    fn is_local(&self) -> bool;
+}
+
 pub struct UdpSender {
     socket: UdpSocket,
     address: SocketAddr
 }
 
 impl UdpSender {
-    /// Creates an [UdpSender] binded on localhost
+    /// Creates an [UdpSender] binded on localhost for a loopback target, or on all interfaces otherwise
     ///
     /// # Arguments
     /// * `target` - the target socket address
     pub fn new(target: SocketAddr) -> Result<Self, std::io::Error> {
-        let ip_address: IpAddr = if target.is_ipv4() {IpAddr::V4(Ipv4Addr::LOCALHOST)} else {IpAddr::V6(Ipv6Addr::LOCALHOST)};
-        Ok(Self {socket: UdpSocket::bind(SocketAddr::new(ip_address, 0))?, address: target})
+        Self::builder(target).build()
+    }
+
+    /// Returns an [UdpSenderBuilder] to configure the local address of an [UdpSender]
+    ///
+    /// # Arguments
+    /// * `target` - the target socket address
+    pub fn builder(target: SocketAddr) -> UdpSenderBuilder {
+        UdpSenderBuilder {
+            target,
+            local_address: None,
+        }
+    }
+
+    /// Returns the socket address the [UdpSender] is binded to
+    pub fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
+        self.socket.local_addr()
+    }
+
+    /// Creates an [UdpSender] sending to a multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the multicast group's socket address
+    /// * `options` - the [MulticastOptions]
+    pub fn multicast(group: SocketAddr, options: MulticastOptions) -> Result<Self, std::io::Error> {
+        if !group.ip().is_multicast() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a multicast address"));
+        }
+
+        let socket = match group {
+            SocketAddr::V4(_) => {
+                let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0))?;
+                socket.set_multicast_ttl_v4(options.ttl)?;
+                socket.set_multicast_loop_v4(options.loopback)?;
+                socket2::SockRef::from(&socket).set_multicast_if_v4(&options.interface_v4)?;
+                socket
+            },
+            SocketAddr::V6(_) => {
+                let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0))?;
+                socket2::SockRef::from(&socket).set_multicast_hops_v6(options.ttl)?;
+                socket.set_multicast_loop_v6(options.loopback)?;
+                socket2::SockRef::from(&socket).set_multicast_if_v6(options.interface_v6)?;
+                socket
+            },
+        };
+
+        Ok(Self {socket, address: group})
+    }
+
+    /// Creates an [UdpSender] sending to a broadcast address
+    ///
+    /// # Arguments
+    /// * `target` - the broadcast socket address, such as `255.255.255.255:3333` or a subnet's broadcast address
+    pub fn broadcast(target: SocketAddrV4) -> Result<Self, std::io::Error> {
+        let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0))?;
+        socket.set_broadcast(true)?;
+        Ok(Self {socket, address: SocketAddr::V4(target)})
+    }
+}
+
+/// A builder of [UdpSender]
+pub struct UdpSenderBuilder {
+    target: SocketAddr,
+    local_address: Option<SocketAddr>,
+}
+
+impl UdpSenderBuilder {
+    /// Binds the [UdpSender] to an explicit local address and port instead of one based on the target
+    ///
+    /// # Arguments
+    /// * `local_address` - the local socket address, of the same IP version as the target
+    pub fn with_local_addr(mut self, local_address: SocketAddr) -> Self {
+        self.local_address = Some(local_address);
+        self
+    }
+
+    /// Creates the [UdpSender]
+    ///
+    /// Without an explicit local address, the socket is binded on localhost for a loopback target
+    /// and on all interfaces otherwise, with a port chosen by the system
+    pub fn build(self) -> Result<UdpSender, std::io::Error> {
+        let local_address = match self.local_address {
+            Some(local_address) if local_address.is_ipv4() != self.target.is_ipv4() => {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "local and target addresses have different IP versions"));
+            },
+            Some(local_address) => local_address,
+            None => {
+                let ip_address = match (self.target.ip(), self.target.ip().is_loopback()) {
+                    (IpAddr::V4(_), true) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    (IpAddr::V4(_), false) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                    (IpAddr::V6(_), true) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                    (IpAddr::V6(_), false) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+                };
+
+                SocketAddr::new(ip_address, 0)
+            },
+        };
+
+        Ok(UdpSender {socket: UdpSocket::bind(local_address)?, address: self.target})
+    }
+}
+
+/// Options of an [UdpSender] sending to a multicast group
+#[derive(Debug, Clone, Copy)]
+pub struct MulticastOptions {
+    /// The time-to-live (or IPv6 hop limit) of the sent packets, 1 keeping them on the local network
+    pub ttl: u32,
+    /// Whether the sent packets are looped back to the receivers of the sending host
+    pub loopback: bool,
+    /// The address of the IPv4 interface to send from, [Ipv4Addr::UNSPECIFIED] letting the system choose
+    pub interface_v4: Ipv4Addr,
+    /// The index of the IPv6 interface to send from, 0 letting the system choose
+    pub interface_v6: u32,
+}
+
+impl Default for MulticastOptions {
+    fn default() -> Self {
+        Self {
+            ttl: 1,
+            loopback: true,
+            interface_v4: Ipv4Addr::UNSPECIFIED,
+            interface_v6: 0,
+        }
     }
 }
 
-impl SendOsc<OscPacket, OscError> for UdpSender {
+impl SendOsc<OscPacket, OscSenderError> for UdpSender {
     /// Sends an [OscPacket] over UDP.
-    /// Returns an [OscError] if packet's encoding fails
+    /// Returns an [OscSenderError] if packet's encoding or sending fails
     ///
     /// # Arguments
     /// * `packet` - a reference to an [OscPacket]
-    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscError> {
-        let buffer = encoder::encode(packet)?;
-        self.socket.send_to(&buffer, self.address).unwrap();
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let buffer = encoder::encode(packet).map_err(OscSenderError::Encode)?;
+        self.socket.send_to(&buffer, self.address).map_err(OscSenderError::Send)?;
         Ok(())
     }
 
@@ -62,9 +204,437 @@ impl SendOsc<OscPacket, OscError> for UdpSender {
     }
 }
 
+/// The interval at which an [AcceptThread] polls its nonblocking listener
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The duration after which a stream client which stopped reading is dropped
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A thread accepting the clients of a stream sender, stopped and joined when dropped, which closes its listener
+struct AcceptThread {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl AcceptThread {
+    /// Spawns a thread polling a nonblocking listener until dropped
+    ///
+    /// # Arguments
+    /// * `accept` - accepts a single client, returning false when no client is waiting
+    fn spawn(mut accept: impl FnMut() -> bool + Send + 'static) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || while !stopped.load(Ordering::SeqCst) {
+            if !accept() {
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        });
+
+        Self {stop, handle: Some(handle)}
+    }
+}
+
+impl Drop for AcceptThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+pub struct TcpSender {
+    address: SocketAddr,
+    framing: Framing,
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+    _accept_thread: AcceptThread,
+}
+
+impl TcpSender {
+    /// Creates a [TcpSender] listening on a socket address
+    ///
+    /// Clients are accepted in a background thread, stopped when the sender is dropped. They are dropped as soon as
+    /// sending to them fails, or when they stop reading for 500 ms
+    ///
+    /// # Arguments
+    /// * `address` - the socket address to listen on
+    /// * `framing` - the [Framing] of the sent packets
+    pub fn new(address: SocketAddr, framing: Framing) -> Result<Self, std::io::Error> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let accepted_clients = Arc::clone(&clients);
+
+        // Errors other than WouldBlock concern a single pending client, so accepting is retried on the next poll
+        let accept_thread = AcceptThread::spawn(move || match listener.accept() {
+            Ok((stream, _)) => {
+                let configured = stream.set_nonblocking(false)
+                    .and_then(|_| stream.set_nodelay(true))
+                    .and_then(|_| stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)));
+
+                if configured.is_ok() {
+                    accepted_clients.lock().unwrap().push(stream);
+                }
+
+                true
+            },
+            Err(_) => false,
+        });
+
+        Ok(Self {address, framing, clients, _accept_thread: accept_thread})
+    }
+
+    /// Returns the socket address the [TcpSender] is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the number of connected clients
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+}
+
+impl SendOsc<OscPacket, OscSenderError> for TcpSender {
+    /// Sends an [OscPacket] to every connected client, removing the disconnected ones and the ones which stopped reading.
+    /// Returns an [OscSenderError] if packet's encoding fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let buffer = self.framing.encode(&encoder::encode(packet).map_err(OscSenderError::Encode)?);
+        self.clients.lock().unwrap().retain_mut(|stream| stream.write_all(&buffer).is_ok());
+        Ok(())
+    }
+
+    /// Returns true if at least one client is connected
+    fn is_connected(&self) -> bool {
+        self.client_count() > 0
+    }
+
+    /// Return true if listening on a loopback address
+    fn is_local(&self) -> bool {
+        self.address.ip().is_loopback()
+    }
+}
+
+/// Sends OSC packets as binary WebSocket messages, such as expected by browser-based TUIO clients
+#[cfg(feature = "websocket")]
+pub struct WebSocketSender {
+    address: SocketAddr,
+    clients: Arc<Mutex<Vec<tungstenite::WebSocket<TcpStream>>>>,
+    _accept_thread: AcceptThread,
+}
+
+#[cfg(feature = "websocket")]
+impl WebSocketSender {
+    /// Creates a [WebSocketSender] listening on a socket address
+    ///
+    /// Clients are accepted in a background thread, stopped when the sender is dropped. They are dropped as soon as
+    /// sending to them fails, or when they stop reading for 500 ms
+    ///
+    /// # Arguments
+    /// * `address` - the socket address to listen on
+    pub fn new(address: SocketAddr) -> Result<Self, std::io::Error> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let accepted_clients = Arc::downgrade(&clients);
+
+        // Errors other than WouldBlock concern a single pending client, so accepting is retried on the next poll
+        let accept_thread = AcceptThread::spawn(move || match listener.accept() {
+            Ok((stream, _)) => {
+                let accepted_clients = accepted_clients.clone();
+
+                // Handshakes in their own thread so that a slow client does not delay the others, the read timeout
+                // bounding the handshake
+                thread::spawn(move || {
+                    let configured = stream.set_nonblocking(false)
+                        .and_then(|_| stream.set_nodelay(true))
+                        .and_then(|_| stream.set_read_timeout(Some(CLIENT_WRITE_TIMEOUT)))
+                        .and_then(|_| stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)));
+
+                    if configured.is_err() {
+                        return;
+                    }
+
+                    if let (Ok(websocket), Some(clients)) = (tungstenite::accept(stream), accepted_clients.upgrade()) {
+                        clients.lock().unwrap().push(websocket);
+                    }
+                });
+
+                true
+            },
+            Err(_) => false,
+        });
+
+        Ok(Self {address, clients, _accept_thread: accept_thread})
+    }
+
+    /// Returns the socket address the [WebSocketSender] is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the number of connected clients
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl SendOsc<OscPacket, OscSenderError> for WebSocketSender {
+    /// Sends an [OscPacket] as a binary message to every connected client, removing the disconnected ones and the ones
+    /// which stopped reading.
+    /// Returns an [OscSenderError] if packet's encoding fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let buffer = encoder::encode(packet).map_err(OscSenderError::Encode)?;
+        self.clients.lock().unwrap().retain_mut(|websocket| websocket.send(tungstenite::Message::Binary(buffer.clone())).is_ok());
+        Ok(())
+    }
+
+    /// Returns true if at least one client is connected
+    fn is_connected(&self) -> bool {
+        self.client_count() > 0
+    }
+
+    /// Return true if listening on a loopback address
+    fn is_local(&self) -> bool {
+        self.address.ip().is_loopback()
+    }
+}
+
+/// Sends OSC packets to a Unix datagram socket
+#[cfg(unix)]
+pub struct UnixDatagramSender {
+    socket: UnixDatagram,
+    path: PathBuf,
+}
+
+#[cfg(unix)]
+impl UnixDatagramSender {
+    /// Creates an [UnixDatagramSender]
+    ///
+    /// # Arguments
+    /// * `path` - the path of the receiver's socket
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        Ok(Self {socket: UnixDatagram::unbound()?, path: path.as_ref().into()})
+    }
+}
+
+#[cfg(unix)]
+impl SendOsc<OscPacket, OscSenderError> for UnixDatagramSender {
+    /// Sends an [OscPacket] to the receiver's socket.
+    /// Returns an [OscSenderError] if packet's encoding or sending fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let buffer = encoder::encode(packet).map_err(OscSenderError::Encode)?;
+        self.socket.send_to(&buffer, &self.path).map_err(OscSenderError::Send)?;
+        Ok(())
+    }
+
+    /// Always returns true because datagram sockets are connectionless
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    /// Always returns true because Unix sockets are local
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+/// Sends framed OSC packets to the clients of a Unix stream socket
+#[cfg(unix)]
+pub struct UnixStreamSender {
+    path: PathBuf,
+    framing: Framing,
+    clients: Arc<Mutex<Vec<UnixStream>>>,
+    _accept_thread: AcceptThread,
+}
+
+#[cfg(unix)]
+impl UnixStreamSender {
+    /// Creates an [UnixStreamSender] listening on a socket file, removed when the sender is dropped
+    ///
+    /// Clients are accepted in a background thread, stopped when the sender is dropped. They are dropped as soon as
+    /// sending to them fails, or when they stop reading for 500 ms
+    ///
+    /// # Arguments
+    /// * `path` - the path of the socket file to create
+    /// * `framing` - the [Framing] of the sent packets, OSC 1.1 using [Framing::Slip]
+    pub fn new(path: impl AsRef<Path>, framing: Framing) -> Result<Self, std::io::Error> {
+        let listener = UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let accepted_clients = Arc::clone(&clients);
+
+        // Errors other than WouldBlock concern a single pending client, so accepting is retried on the next poll
+        let accept_thread = AcceptThread::spawn(move || match listener.accept() {
+            Ok((stream, _)) => {
+                let configured = stream.set_nonblocking(false)
+                    .and_then(|_| stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)));
+
+                if configured.is_ok() {
+                    accepted_clients.lock().unwrap().push(stream);
+                }
+
+                true
+            },
+            Err(_) => false,
+        });
+
+        Ok(Self {path: path.as_ref().into(), framing, clients, _accept_thread: accept_thread})
+    }
+
+    /// Returns the number of connected clients
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+}
+
+#[cfg(unix)]
+impl SendOsc<OscPacket, OscSenderError> for UnixStreamSender {
+    /// Sends an [OscPacket] to every connected client, removing the disconnected ones and the ones which stopped reading.
+    /// Returns an [OscSenderError] if packet's encoding fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let buffer = self.framing.encode(&encoder::encode(packet).map_err(OscSenderError::Encode)?);
+        self.clients.lock().unwrap().retain_mut(|stream| stream.write_all(&buffer).is_ok());
+        Ok(())
+    }
+
+    /// Returns true if at least one client is connected
+    fn is_connected(&self) -> bool {
+        self.client_count() > 0
+    }
+
+    /// Always returns true because Unix sockets are local
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(unix)]
+impl Drop for UnixStreamSender {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Sends OSC packets to an in-process [ChannelReceiver](crate::osc_receiver::ChannelReceiver), without any socket
+///
+/// Created by [ChannelReceiver::sender](crate::osc_receiver::ChannelReceiver::sender)
+pub struct ChannelSender {
+    sender: Sender<Option<OscPacket>>,
+    connected: Arc<AtomicBool>,
+}
+
+impl ChannelSender {
+    pub(crate) fn new(sender: Sender<Option<OscPacket>>, connected: Arc<AtomicBool>) -> Self {
+        Self {sender, connected}
+    }
+}
+
+impl SendOsc<OscPacket, OscSenderError> for ChannelSender {
+    /// Sends an [OscPacket] to the receiver.
+    /// Returns an [OscSenderError] if the receiver has been dropped
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        self.sender.send(Some(packet.clone())).map_err(|_| OscSenderError::Send(std::io::ErrorKind::BrokenPipe.into()))
+    }
+
+    /// Returns true if the receiver is connected
+    fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Always returns true because the receiver lives in the same process
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+/// Sends OSC packets through an UDP socket registered in a tokio runtime
+#[cfg(feature = "async")]
+pub struct AsyncUdpSender {
+    socket: tokio::net::UdpSocket,
+    address: SocketAddr,
+}
+
+#[cfg(feature = "async")]
+impl AsyncUdpSender {
+    /// Creates an [AsyncUdpSender] binded on localhost for a loopback target, or on all interfaces otherwise
+    ///
+    /// Must be called from within a tokio runtime
+    /// # Arguments
+    /// * `target` - the target socket address
+    pub fn new(target: SocketAddr) -> Result<Self, std::io::Error> {
+        Self::from_udp_sender(UdpSender::new(target)?)
+    }
+
+    /// Creates an [AsyncUdpSender] from an [UdpSender], such as a multicast or broadcast one
+    ///
+    /// Must be called from within a tokio runtime
+    /// # Arguments
+    /// * `sender` - the [UdpSender] to convert
+    pub fn from_udp_sender(sender: UdpSender) -> Result<Self, std::io::Error> {
+        sender.socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            socket: tokio::net::UdpSocket::from_std(sender.socket)?,
+            address: sender.address,
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
+        self.socket.local_addr()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncSendOsc<OscPacket, OscSenderError> for AsyncUdpSender {
+    /// Sends an [OscPacket] over UDP.
+    /// Returns an [OscSenderError] if packet's encoding or sending fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet<'a>(&'a self, packet: &'a OscPacket) -> BoxFuture<'a, Result<(), OscSenderError>> {
+        Box::pin(async move {
+            let buffer = encoder::encode(packet).map_err(OscSenderError::Encode)?;
+            self.socket.send_to(&buffer, self.address).await.map_err(OscSenderError::Send)?;
+            Ok(())
+        })
+    }
+
+    /// Always returns true because UDP is connectionless
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    fn is_local(&self) -> bool {
+        self.address.ip().is_loopback()
+    }
+}
+
 /// TUIO Server which keeps track of all TUIO elements and which send TUIO messages over the network
 pub struct Server {
-    sender_list: Vec<Box<dyn SendOsc<OscPacket, OscError>>>,
+    sender_list: Vec<Box<dyn SendOsc<OscPacket, OscSenderError> + Send>>,
+    #[cfg(feature = "async")]
+    async_sender_list: Vec<Box<dyn AsyncSendOsc<OscPacket, OscSenderError> + Send + Sync>>,
     source_name: String,
     session_id: i32,
     object_map: IndexMap<i32, Object>,
@@ -76,11 +646,34 @@ pub struct Server {
     cursor_updated: bool,
     blob_map: IndexMap<i32, Blob>,
     blob_updated: bool,
+    frame_cursor_25d_ids: Vec<i32>,
+    frame_object_25d_ids: Vec<i32>,
+    frame_blob_25d_ids: Vec<i32>,
+    object_25d_map: IndexMap<i32, Object25D>,
+    object_25d_updated: bool,
+    cursor_25d_map: IndexMap<i32, Cursor25D>,
+    cursor_25d_updated: bool,
+    blob_25d_map: IndexMap<i32, Blob25D>,
+    blob_25d_updated: bool,
+    frame_cursor_3d_ids: Vec<i32>,
+    frame_object_3d_ids: Vec<i32>,
+    frame_blob_3d_ids: Vec<i32>,
+    object_3d_map: IndexMap<i32, Object3D>,
+    object_3d_updated: bool,
+    cursor_3d_map: IndexMap<i32, Cursor3D>,
+    cursor_3d_updated: bool,
+    blob_3d_map: IndexMap<i32, Blob3D>,
+    blob_3d_updated: bool,
+    custom_map: IndexMap<String, IndexMap<i32, CustomProfile>>,
+    custom_updated: HashSet<String>,
+    frame_custom_ids: Vec<i32>,
     instant: Instant,
     last_frame_instant: Instant,
     frame_duration: Duration,
     last_frame_id: AtomicI32,
-    /// Enables the full update of all currently active and inactive [Object]s, [Cursor]s and [Blob]s
+    /// Enables the full update of all currently active and inactive entities of every profile
+    ///
+    /// Otherwise only the entities updated during the frame are sent, the alive list still holding every entity
     pub full_update: bool,
     periodic_messaging: bool,
     update_interval: Duration,
@@ -90,6 +683,20 @@ pub struct Server {
     cursor_update_time: Instant,
     pub blob_profiling: bool,
     blob_update_time: Instant,
+    pub object_25d_profiling: bool,
+    object_25d_update_time: Instant,
+    pub cursor_25d_profiling: bool,
+    cursor_25d_update_time: Instant,
+    pub blob_25d_profiling: bool,
+    blob_25d_update_time: Instant,
+    pub object_3d_profiling: bool,
+    object_3d_update_time: Instant,
+    pub cursor_3d_profiling: bool,
+    cursor_3d_update_time: Instant,
+    pub blob_3d_profiling: bool,
+    blob_3d_update_time: Instant,
+    pub custom_profiling: bool,
+    custom_update_time: Instant,
 }
 
 impl Server {
@@ -115,9 +722,27 @@ impl Server {
     ///
     /// # Arguments
     /// * `osc_sender` - a sender implementing [OscSender]
-    pub fn from_osc_sender(osc_sender: impl SendOsc<OscPacket, OscError> + 'static) -> Self {
+    pub fn from_osc_sender(osc_sender: impl SendOsc<OscPacket, OscSenderError> + Send + 'static) -> Self {
+        Self::from_sender_list(vec![Box::new(osc_sender)])
+    }
+
+    /// Creates a TUIO [Server] from an async OSC sender implementing [AsyncSendOsc] trait
+    ///
+    /// Async senders are only used by [Server::commit_frame_async]
+    /// # Arguments
+    /// * `osc_sender` - a sender implementing [AsyncSendOsc]
+    #[cfg(feature = "async")]
+    pub fn from_async_osc_sender(osc_sender: impl AsyncSendOsc<OscPacket, OscSenderError> + Send + Sync + 'static) -> Self {
+        let mut server = Self::from_sender_list(Vec::new());
+        server.add_async_osc_sender(osc_sender);
+        server
+    }
+
+    fn from_sender_list(sender_list: Vec<Box<dyn SendOsc<OscPacket, OscSenderError> + Send>>) -> Self {
         Self {
-            sender_list: vec![Box::new(osc_sender)],
+            sender_list,
+            #[cfg(feature = "async")]
+            async_sender_list: Vec::new(),
             source_name: String::new(),
             session_id: -1,
             object_map: IndexMap::new(),
@@ -142,6 +767,41 @@ impl Server {
             frame_cursor_ids: Vec::new(),
             frame_object_ids: Vec::new(),
             frame_blob_ids: Vec::new(),
+            object_25d_map: IndexMap::new(),
+            object_25d_updated: false,
+            cursor_25d_map: IndexMap::new(),
+            cursor_25d_updated: false,
+            blob_25d_map: IndexMap::new(),
+            blob_25d_updated: false,
+            object_25d_profiling: true,
+            object_25d_update_time: Instant::now(),
+            cursor_25d_profiling: true,
+            cursor_25d_update_time: Instant::now(),
+            blob_25d_profiling: true,
+            blob_25d_update_time: Instant::now(),
+            frame_cursor_25d_ids: Vec::new(),
+            frame_object_25d_ids: Vec::new(),
+            frame_blob_25d_ids: Vec::new(),
+            object_3d_map: IndexMap::new(),
+            object_3d_updated: false,
+            cursor_3d_map: IndexMap::new(),
+            cursor_3d_updated: false,
+            blob_3d_map: IndexMap::new(),
+            blob_3d_updated: false,
+            object_3d_profiling: true,
+            object_3d_update_time: Instant::now(),
+            cursor_3d_profiling: true,
+            cursor_3d_update_time: Instant::now(),
+            blob_3d_profiling: true,
+            blob_3d_update_time: Instant::now(),
+            frame_cursor_3d_ids: Vec::new(),
+            frame_object_3d_ids: Vec::new(),
+            frame_blob_3d_ids: Vec::new(),
+            custom_map: IndexMap::new(),
+            custom_updated: HashSet::new(),
+            frame_custom_ids: Vec::new(),
+            custom_profiling: true,
+            custom_update_time: Instant::now(),
         }
     }
 
@@ -149,16 +809,30 @@ impl Server {
     ///
     /// # Arguments
     /// * `osc_sender` - a sender implementing [OscSender]
-    pub fn add_osc_sender(&mut self, osc_sender: impl SendOsc<OscPacket, OscError> + 'static) {
+    pub fn add_osc_sender(&mut self, osc_sender: impl SendOsc<OscPacket, OscSenderError> + Send + 'static) {
         self.sender_list.push(Box::new(osc_sender));
     }
 
+    /// Adds an async OSC sender implementing [AsyncSendOsc] trait
+    ///
+    /// Async senders are only used by [Server::commit_frame_async]
+    /// # Arguments
+    /// * `osc_sender` - a sender implementing [AsyncSendOsc]
+    #[cfg(feature = "async")]
+    pub fn add_async_osc_sender(&mut self, osc_sender: impl AsyncSendOsc<OscPacket, OscSenderError> + Send + Sync + 'static) {
+        self.async_sender_list.push(Box::new(osc_sender));
+    }
+
     /// Sets the server source name which is sent through the TUIO source message
     ///
     /// # Arguments
     /// * `name` - the name of the source
     pub fn set_source_name(&mut self, name: &str) {
-        let source = if self.sender_list[0].is_local() {String::from("local")} else {
+        let is_local = self.sender_list.first().map(|sender| sender.is_local());
+        #[cfg(feature = "async")]
+        let is_local = is_local.or_else(|| self.async_sender_list.first().map(|sender| sender.is_local()));
+
+        let source = if is_local.unwrap_or(true) {String::from("local")} else {
             match local_ip() {
                 Ok(ip) => ip.to_string(),
                 Err(_) => String::new()
@@ -218,7 +892,6 @@ impl Server {
         if let Some(object) = self.object_map.get_mut(&session_id) {
             object.update(self.frame_duration, Position{x, y}, angle);
             self.frame_object_ids.push(session_id);
-            self.frame_object_ids.push(session_id);
             self.object_updated = true;
         }
     }
@@ -306,7 +979,6 @@ impl Server {
         if let Some(blob) = self.blob_map.get_mut(&session_id) {
             blob.update(self.frame_duration, Position{x, y}, angle, width, height, area);
             self.frame_blob_ids.push(session_id);
-            self.frame_blob_ids.push(session_id);
             self.blob_updated = true;
         }
     }
@@ -321,7 +993,324 @@ impl Server {
         }
     }
 
-    /// Initializes a new frame.
+    /// Creates a TUIO [Object25D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `class_id` - a unique identifier that can be associated with a tangible object
+    /// * `x` - the object's x position
+    /// * `y` - the object's y position
+    /// * `z` - the object's height above the surface
+    /// * `angle` - the object's angle
+    pub fn create_object_25d(&mut self, class_id: i32, x: f32, y: f32, z: f32, angle: f32) -> i32 {
+        let session_id = self.get_session_id();
+
+        let object = Object25D::new(session_id, class_id, Position3D{x, y, z}, angle);
+        self.object_25d_map.insert(session_id, object);
+        self.frame_object_25d_ids.push(session_id);
+        self.object_25d_updated = true;
+        session_id
+    }
+
+    /// Updates a TUIO [Object25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the object's session id
+    /// * `x` - the new object's x position
+    /// * `y` - the new object's y position
+    /// * `z` - the new object's height above the surface
+    /// * `angle` - the new object's angle
+    pub fn update_object_25d(&mut self, session_id: i32, x: f32, y: f32, z: f32, angle: f32) {
+        if let Some(object) = self.object_25d_map.get_mut(&session_id) {
+            object.update(self.frame_duration, Position3D{x, y, z}, angle);
+            self.frame_object_25d_ids.push(session_id);
+            self.object_25d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Object25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the object's session id
+    pub fn remove_object_25d(&mut self, session_id: i32) {
+        if self.object_25d_map.remove(&session_id).is_some() {
+            self.object_25d_updated = true;
+        }
+    }
+
+    /// Creates a TUIO [Cursor25D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `x` - the cursor's x position
+    /// * `y` - the cursor's y position
+    /// * `z` - the cursor's height above the surface
+    pub fn create_cursor_25d(&mut self, x: f32, y: f32, z: f32) -> i32 {
+        let session_id = self.get_session_id();
+
+        let cursor = Cursor25D::new(session_id, Position3D{x, y, z});
+        self.cursor_25d_map.insert(session_id, cursor);
+        self.frame_cursor_25d_ids.push(session_id);
+        self.cursor_25d_updated = true;
+        session_id
+    }
+
+    /// Updates a TUIO [Cursor25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the cursor's session id
+    /// * `x` - the new cursor's x position
+    /// * `y` - the new cursor's y position
+    /// * `z` - the new cursor's height above the surface
+    pub fn update_cursor_25d(&mut self, session_id: i32, x: f32, y: f32, z: f32) {
+        if let Some(cursor) = self.cursor_25d_map.get_mut(&session_id) {
+            cursor.update(self.frame_duration, Position3D{x, y, z});
+            self.frame_cursor_25d_ids.push(session_id);
+            self.cursor_25d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Cursor25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the cursor's session id
+    pub fn remove_cursor_25d(&mut self, session_id: i32) {
+        if self.cursor_25d_map.remove(&session_id).is_some() {
+            self.cursor_25d_updated = true;
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    /// Creates a TUIO [Blob25D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `x` - the blob's x position
+    /// * `y` - the blob's y position
+    /// * `z` - the blob's height above the surface
+    /// * `angle` - the blob's angle
+    /// * `width` - the blob's width
+    /// * `height` - the blob's height
+    /// * `area` - the blob's area
+    pub fn create_blob_25d(&mut self, x: f32, y: f32, z: f32, angle: f32, width: f32, height: f32, area: f32) -> i32 {
+        let session_id = self.get_session_id();
+
+        let blob = Blob25D::new(session_id, Position3D{x, y, z}, angle, width, height, area);
+        self.blob_25d_map.insert(session_id, blob);
+        self.frame_blob_25d_ids.push(session_id);
+        self.blob_25d_updated = true;
+        session_id
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    /// Updates a TUIO [Blob25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the blob's session id
+    /// * `x` - the new blob's x position
+    /// * `y` - the new blob's y position
+    /// * `z` - the new blob's height above the surface
+    /// * `angle` - the new blob's angle
+    /// * `width` - the new blob's width
+    /// * `height` - the new blob's height
+    /// * `area` - the new blob's area
+    pub fn update_blob_25d(&mut self, session_id: i32, x: f32, y: f32, z: f32, angle: f32, width: f32, height: f32, area: f32) {
+        if let Some(blob) = self.blob_25d_map.get_mut(&session_id) {
+            blob.update(self.frame_duration, Position3D{x, y, z}, angle, width, height, area);
+            self.frame_blob_25d_ids.push(session_id);
+            self.blob_25d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Blob25D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the blob's session id
+    pub fn remove_blob_25d(&mut self, session_id: i32) {
+        if self.blob_25d_map.remove(&session_id).is_some() {
+            self.blob_25d_updated = true;
+        }
+    }
+
+    /// Creates a TUIO [Object3D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `class_id` - a unique identifier that can be associated with a tangible object
+    /// * `position` - the object's [Position3D]
+    /// * `angle` - the object's Euler angles
+    pub fn create_object_3d(&mut self, class_id: i32, position: Position3D, angle: Angle3D) -> i32 {
+        let session_id = self.get_session_id();
+
+        let object = Object3D::new(session_id, class_id, position, angle);
+        self.object_3d_map.insert(session_id, object);
+        self.frame_object_3d_ids.push(session_id);
+        self.object_3d_updated = true;
+        session_id
+    }
+
+    /// Updates a TUIO [Object3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the object's session id
+    /// * `position` - the new object's [Position3D]
+    /// * `angle` - the new object's Euler angles
+    pub fn update_object_3d(&mut self, session_id: i32, position: Position3D, angle: Angle3D) {
+        if let Some(object) = self.object_3d_map.get_mut(&session_id) {
+            object.update(self.frame_duration, position, angle);
+            self.frame_object_3d_ids.push(session_id);
+            self.object_3d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Object3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the object's session id
+    pub fn remove_object_3d(&mut self, session_id: i32) {
+        if self.object_3d_map.remove(&session_id).is_some() {
+            self.object_3d_updated = true;
+        }
+    }
+
+    /// Creates a TUIO [Cursor3D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `x` - the cursor's x position
+    /// * `y` - the cursor's y position
+    /// * `z` - the cursor's z position
+    pub fn create_cursor_3d(&mut self, x: f32, y: f32, z: f32) -> i32 {
+        let session_id = self.get_session_id();
+
+        let cursor = Cursor3D::new(session_id, Position3D{x, y, z});
+        self.cursor_3d_map.insert(session_id, cursor);
+        self.frame_cursor_3d_ids.push(session_id);
+        self.cursor_3d_updated = true;
+        session_id
+    }
+
+    /// Updates a TUIO [Cursor3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the cursor's session id
+    /// * `x` - the new cursor's x position
+    /// * `y` - the new cursor's y position
+    /// * `z` - the new cursor's z position
+    pub fn update_cursor_3d(&mut self, session_id: i32, x: f32, y: f32, z: f32) {
+        if let Some(cursor) = self.cursor_3d_map.get_mut(&session_id) {
+            cursor.update(self.frame_duration, Position3D{x, y, z});
+            self.frame_cursor_3d_ids.push(session_id);
+            self.cursor_3d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Cursor3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the cursor's session id
+    pub fn remove_cursor_3d(&mut self, session_id: i32) {
+        if self.cursor_3d_map.remove(&session_id).is_some() {
+            self.cursor_3d_updated = true;
+        }
+    }
+
+    /// Creates a TUIO [Blob3D] and returns its session_id
+    ///
+    /// # Arguments
+    /// * `position` - the blob's [Position3D]
+    /// * `angle` - the blob's Euler angles
+    /// * `width` - the blob's width
+    /// * `height` - the blob's height
+    /// * `depth` - the blob's depth
+    /// * `volume` - the blob's volume
+    pub fn create_blob_3d(&mut self, position: Position3D, angle: Angle3D, width: f32, height: f32, depth: f32, volume: f32) -> i32 {
+        let session_id = self.get_session_id();
+
+        let blob = Blob3D::new(session_id, position, angle, width, height, depth, volume);
+        self.blob_3d_map.insert(session_id, blob);
+        self.frame_blob_3d_ids.push(session_id);
+        self.blob_3d_updated = true;
+        session_id
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    /// Updates a TUIO [Blob3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the blob's session id
+    /// * `position` - the new blob's [Position3D]
+    /// * `angle` - the new blob's Euler angles
+    /// * `width` - the new blob's width
+    /// * `height` - the new blob's height
+    /// * `depth` - the new blob's depth
+    /// * `volume` - the new blob's volume
+    pub fn update_blob_3d(&mut self, session_id: i32, position: Position3D, angle: Angle3D, width: f32, height: f32, depth: f32, volume: f32) {
+        if let Some(blob) = self.blob_3d_map.get_mut(&session_id) {
+            blob.update(self.frame_duration, position, angle, width, height, depth, volume);
+            self.frame_blob_3d_ids.push(session_id);
+            self.blob_3d_updated = true;
+        }
+    }
+
+    /// Removes a TUIO [Blob3D]
+    ///
+    /// # Arguments
+    /// * `session_id` - the blob's session id
+    pub fn remove_blob_3d(&mut self, session_id: i32) {
+        if self.blob_3d_map.remove(&session_id).is_some() {
+            self.blob_3d_updated = true;
+        }
+    }
+
+    /// Creates a custom profile entity sent as `/tuio/_[format]` and returns its session_id
+    ///
+    /// # Arguments
+    /// * `format` - the profile's format string, starting with `s`
+    /// * `values` - the attribute values following the session ID, in the order of the format string
+    ///
+    /// Returns a [CustomProfileError] if the format cannot be decoded or if the values do not match it
+    pub fn create_custom(&mut self, format: &str, values: Vec<CustomValue>) -> Result<i32, CustomProfileError> {
+        custom_profile::validate(format, &values)?;
+        let session_id = self.get_session_id();
+
+        let custom = CustomProfile::new(session_id, format, values);
+        self.custom_map.entry(format.into()).or_default().insert(session_id, custom);
+        self.custom_updated.insert(format.into());
+        self.frame_custom_ids.push(session_id);
+        Ok(session_id)
+    }
+
+    /// Updates a custom profile entity
+    ///
+    /// # Arguments
+    /// * `session_id` - the entity's session id
+    /// * `values` - the new attribute values following the session ID
+    ///
+    /// Returns a [CustomProfileError] if the values do not match the entity's format
+    pub fn update_custom(&mut self, session_id: i32, values: Vec<CustomValue>) -> Result<(), CustomProfileError> {
+        for (format, custom_collection) in self.custom_map.iter_mut() {
+            if let Some(custom) = custom_collection.get_mut(&session_id) {
+                custom_profile::validate(format, &values)?;
+                custom.update(values);
+                self.custom_updated.insert(format.clone());
+                self.frame_custom_ids.push(session_id);
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes a custom profile entity
+    ///
+    /// # Arguments
+    /// * `session_id` - the entity's session id
+    pub fn remove_custom(&mut self, session_id: i32) {
+        for (format, custom_collection) in self.custom_map.iter_mut() {
+            if custom_collection.remove(&session_id).is_some() {
+                self.custom_updated.insert(format.clone());
+                return;
+            }
+        }
+    }
+
+    /// Initializes a new frame.
     pub fn init_frame(&mut self) {
         self.frame_duration = self.instant.duration_since(self.last_frame_instant);
         self.last_frame_instant = Instant::now();
@@ -330,17 +1319,55 @@ impl Server {
 
     /// Commits the current frame.
     /// 
-    /// Generates and sends TUIO messages of all currently active and updated [Object]s, [Cursor]s and [Blob]s
-    pub fn commit_frame(&mut self) {
+    /// Generates and sends TUIO messages of all currently active and updated [Object]s, [Cursor]s and [Blob]s.
+    /// Every packet is sent to every sender, the first [OscSenderError] being returned
+    pub fn commit_frame(&mut self) -> Result<(), OscSenderError> {
+        let mut result = Ok(());
+
+        for packet in self.frame_packets() {
+            result = result.and(self.deliver_osc_packet(&packet));
+        }
+
+        result
+    }
+
+    /// Commits the current frame asynchronously.
+    ///
+    /// Generates TUIO messages of all currently active and updated [Object]s, [Cursor]s and [Blob]s,
+    /// sending them only through the async OSC senders, the blocking OSC senders being left to [Server::commit_frame].
+    /// Every packet is sent to every async sender, the first [OscSenderError] being returned
+    #[cfg(feature = "async")]
+    pub async fn commit_frame_async(&mut self) -> Result<(), OscSenderError> {
+        let mut result = Ok(());
+
+        for packet in self.frame_packets() {
+            result = result.and(Self::deliver_osc_packet_async(&self.async_sender_list, &packet).await);
+        }
+
+        result
+    }
+
+    /// Closes the session of the async OSC senders
+    ///
+    /// Sends the same end of session bundles as the [Server] does through its blocking OSC senders once dropped,
+    /// the first [OscSenderError] being returned
+    #[cfg(feature = "async")]
+    pub async fn close(&mut self) -> Result<(), OscSenderError> {
+        let mut result = Ok(());
+
+        for packet in self.end_of_session_packets() {
+            result = result.and(Self::deliver_osc_packet_async(&self.async_sender_list, &packet).await);
+        }
+
+        result
+    }
+
+    /// Generates the bundles of the current frame, resetting the updated flags
+    fn frame_packets(&mut self) -> Vec<OscPacket> {
+        let mut packets = Vec::new();
+
         if self.object_updated || (self.periodic_messaging && self.object_profiling && self.object_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
-            if self.full_update {
-                let object_collection = self.frame_object_ids.iter().map(|id| self.object_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            }
-            else {
-                let object_collection = self.object_map.values();
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            }
+            packets.push(self.profile_bundle("/tuio/2Dobj", &self.object_map, &self.frame_object_ids));
             
             self.frame_object_ids.clear();
             self.object_update_time = self.last_frame_instant;
@@ -348,13 +1375,7 @@ impl Server {
         }
 
         if self.cursor_updated || (self.periodic_messaging && self.cursor_profiling && self.cursor_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
-            if !self.full_update {
-                let cursor_collection = self.frame_cursor_ids.iter().map(|id| self.cursor_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            } else {
-                let cursor_collection = self.cursor_map.iter().map(|(_, cursor)| cursor);
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            };
+            packets.push(self.profile_bundle("/tuio/2Dcur", &self.cursor_map, &self.frame_cursor_ids));
 
             self.frame_cursor_ids.clear();
             self.cursor_update_time = self.last_frame_instant;
@@ -362,127 +1383,202 @@ impl Server {
         }
         
         if self.blob_updated || (self.periodic_messaging && self.blob_profiling && self.blob_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
-            if !self.full_update {
-                let blob_collection = self.frame_blob_ids.iter().map(|id| self.blob_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            } else {
-                let blob_collection = self.blob_map.values();
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
-            };
+            packets.push(self.profile_bundle("/tuio/2Dblb", &self.blob_map, &self.frame_blob_ids));
             
             self.frame_blob_ids.clear();
             self.blob_update_time = self.last_frame_instant;
             self.blob_updated = false;
         }
+
+        if self.object_25d_updated || (self.periodic_messaging && self.object_25d_profiling && self.object_25d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/25Dobj", &self.object_25d_map, &self.frame_object_25d_ids));
+
+            self.frame_object_25d_ids.clear();
+            self.object_25d_update_time = self.last_frame_instant;
+            self.object_25d_updated = false;
+        }
+
+        if self.cursor_25d_updated || (self.periodic_messaging && self.cursor_25d_profiling && self.cursor_25d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/25Dcur", &self.cursor_25d_map, &self.frame_cursor_25d_ids));
+
+            self.frame_cursor_25d_ids.clear();
+            self.cursor_25d_update_time = self.last_frame_instant;
+            self.cursor_25d_updated = false;
+        }
+
+        if self.blob_25d_updated || (self.periodic_messaging && self.blob_25d_profiling && self.blob_25d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/25Dblb", &self.blob_25d_map, &self.frame_blob_25d_ids));
+
+            self.frame_blob_25d_ids.clear();
+            self.blob_25d_update_time = self.last_frame_instant;
+            self.blob_25d_updated = false;
+        }
+
+        if self.object_3d_updated || (self.periodic_messaging && self.object_3d_profiling && self.object_3d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/3Dobj", &self.object_3d_map, &self.frame_object_3d_ids));
+
+            self.frame_object_3d_ids.clear();
+            self.object_3d_update_time = self.last_frame_instant;
+            self.object_3d_updated = false;
+        }
+
+        if self.cursor_3d_updated || (self.periodic_messaging && self.cursor_3d_profiling && self.cursor_3d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/3Dcur", &self.cursor_3d_map, &self.frame_cursor_3d_ids));
+
+            self.frame_cursor_3d_ids.clear();
+            self.cursor_3d_update_time = self.last_frame_instant;
+            self.cursor_3d_updated = false;
+        }
+
+        if self.blob_3d_updated || (self.periodic_messaging && self.blob_3d_profiling && self.blob_3d_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
+            packets.push(self.profile_bundle("/tuio/3Dblb", &self.blob_3d_map, &self.frame_blob_3d_ids));
+
+            self.frame_blob_3d_ids.clear();
+            self.blob_3d_update_time = self.last_frame_instant;
+            self.blob_3d_updated = false;
+        }
+
+        if self.periodic_messaging && self.custom_profiling && self.custom_update_time.duration_since(self.last_frame_instant) >= self.update_interval {
+            self.custom_updated.extend(self.custom_map.keys().cloned());
+        }
+
+        if !self.custom_updated.is_empty() {
+            for (format, custom_collection) in &self.custom_map {
+                if self.custom_updated.contains(format) {
+                    packets.push(self.profile_bundle(&format!("/tuio/_{}", format), custom_collection, &self.frame_custom_ids));
+                }
+            }
+
+            self.frame_custom_ids.clear();
+            self.custom_update_time = self.last_frame_instant;
+            self.custom_updated.clear();
+        }
+    
+        packets
     }
 
-    pub fn send_full_messages(&self) {
+    /// Builds the bundle of a profile, whose alive list holds every entity
+    ///
+    /// Every entity is sent on a full update, only the entities updated during the frame being sent otherwise
+    /// # Arguments
+    /// * `address` - the profile's OSC address
+    /// * `entity_map` - every entity of the profile
+    /// * `frame_ids` - the session ids of the entities updated during the frame
+    fn profile_bundle<E: EncodeSet>(&self, address: &str, entity_map: &IndexMap<i32, E>, frame_ids: &[i32]) -> OscPacket {
+        let set_args: Vec<(i32, Vec<OscType>)> = if self.full_update {
+            entity_map.values().map(EncodeSet::encode_set).collect()
+        }
+        else {
+            frame_ids.iter().filter_map(|id| entity_map.get(id)).map(EncodeSet::encode_set).collect()
+        };
+
+        OscPacket::Bundle(osc_encode_decode::encode_bundle(address, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst), entity_map.keys().copied(), set_args))
+    }
+
+    /// Sends the whole state, with every entity of every profile
+    ///
+    /// Every packet is sent to every sender, the first [OscSenderError] being returned
+    pub fn send_full_messages(&self) -> Result<(), OscSenderError> {
         let frame_id = self.last_frame_id.load(Ordering::SeqCst);
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(self.object_map.values(), self.source_name.clone(), frame_id)));
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(self.cursor_map.values(), self.source_name.clone(), frame_id)));
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(self.blob_map.values(), self.source_name.clone(), frame_id)));
+        let mut packets = vec![
+            OscPacket::Bundle(OscEncoder::encode_object_bundle(self.object_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_cursor_bundle(self.cursor_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_blob_bundle(self.blob_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_object_25d_bundle(self.object_25d_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_cursor_25d_bundle(self.cursor_25d_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_blob_25d_bundle(self.blob_25d_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_object_3d_bundle(self.object_3d_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_cursor_3d_bundle(self.cursor_3d_map.values(), self.source_name.clone(), frame_id)),
+            OscPacket::Bundle(OscEncoder::encode_blob_3d_bundle(self.blob_3d_map.values(), self.source_name.clone(), frame_id)),
+        ];
+
+        for (format, custom_collection) in &self.custom_map {
+            packets.push(OscPacket::Bundle(OscEncoder::encode_custom_bundle(custom_collection.values(), format, self.source_name.clone(), frame_id)));
+        }
+
+        let mut result = Ok(());
+
+        for packet in &packets {
+            result = result.and(self.deliver_osc_packet(packet));
+        }
+
+        result
     }
     
-    fn deliver_osc_packet(&self, packet: OscPacket) {
+    /// Sends a packet to every OSC sender, even when one of them fails, returning the first [OscSenderError]
+    fn deliver_osc_packet(&self, packet: &OscPacket) -> Result<(), OscSenderError> {
+        let mut result = Ok(());
+
         for sender in &self.sender_list {
-            sender.send_osc_packet(&packet).expect("invalid packet")
+            result = result.and(sender.send_osc_packet(packet));
         }
+
+        result
     }
-}
 
-impl Drop for Server {
-    fn drop(&mut self) {
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(self.source_name.clone())
-            ]
-        });
+    /// Sends a packet to every async OSC sender, even when one of them fails, returning the first [OscSenderError]
+    ///
+    /// Only borrows the async senders, as the blocking ones are not [Sync]
+    #[cfg(feature = "async")]
+    async fn deliver_osc_packet_async(async_sender_list: &[Box<dyn AsyncSendOsc<OscPacket, OscSenderError> + Send + Sync>], packet: &OscPacket) -> Result<(), OscSenderError> {
+        let mut result = Ok(());
 
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![OscType::String("alive".into())]
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dobj".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
-        });
-    
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-           content: vec![
-               source_message,
-               alive_message,
-               frame_message
-           ]
-        });
+        for sender in async_sender_list {
+            result = result.and(sender.send_osc_packet(packet).await);
+        }
 
-        self.deliver_osc_packet(packet);
+        result
+    }
 
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(self.source_name.clone())
-            ]
-        });
+    /// Generates the end of session bundles, with an empty alive list and a -1 frame id for every profile
+    fn end_of_session_packets(&self) -> Vec<OscPacket> {
+        let addresses = ["/tuio/2Dobj", "/tuio/2Dcur", "/tuio/2Dblb", "/tuio/25Dobj", "/tuio/25Dcur", "/tuio/25Dblb", "/tuio/3Dobj", "/tuio/3Dcur", "/tuio/3Dblb"].into_iter().map(String::from)
+            .chain(self.custom_map.keys().map(|format| format!("/tuio/_{}", format)));
 
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![OscType::String("alive".into())]
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dcur".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
-        });
-    
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-           content: vec![
-               source_message,
-               alive_message,
-               frame_message
-           ]
-        });
+        addresses.map(|address| {
+            let source_message = OscPacket::Message(OscMessage {
+                addr: address.clone(),
+                args: vec![
+                    OscType::String("source".into()),
+                    OscType::String(self.source_name.clone())
+                ]
+            });
 
-        self.deliver_osc_packet(packet);
+            let alive_message = OscPacket::Message(OscMessage {
+                addr: address.clone(),
+                args: vec![OscType::String("alive".into())]
+            });
 
-        let source_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![
-                OscType::String("source".into()),
-                OscType::String(self.source_name.clone())
-            ]
-        });
+            let frame_message = OscPacket::Message(OscMessage {
+                addr: address.clone(),
+                args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
+            });
 
-        let alive_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![OscType::String("alive".into())]
-        });
-    
-        let frame_message = OscPacket::Message(OscMessage {
-            addr: "/tuio/2Dblb".into(),
-            args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
-        });
-    
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
-           content: vec![
-               source_message,
-               alive_message,
-               frame_message
-           ]
-        });
+            OscPacket::Bundle(OscBundle {
+               timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"),
+               content: vec![
+                   source_message,
+                   alive_message,
+                   frame_message
+               ]
+            })
+        }).collect()
+    }
+}
 
-        self.deliver_osc_packet(packet);
+impl Drop for Server {
+    fn drop(&mut self) {
+        for packet in self.end_of_session_packets() {
+            // Nothing can be reported from a drop
+            let _ = self.deliver_osc_packet(&packet);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::osc_encode_decode::{DecodeOsc, OscDecoder, Set, TuioBundle};
+
     use super::*;
 
     #[test]
@@ -491,4 +1587,241 @@ mod tests {
         server.session_id = i32::MAX;
         assert_eq!(server.get_session_id(), i32::MIN);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn udp_sender_bind_address() {
+        let local_sender = UdpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333)).unwrap();
+        assert!(local_sender.local_addr().unwrap().ip().is_loopback());
+
+        let remote_sender = UdpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 3333)).unwrap();
+        assert!(remote_sender.local_addr().unwrap().ip().is_unspecified());
+
+        let explicit_sender = UdpSender::builder(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 3333))
+            .with_local_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0))
+            .build()
+            .unwrap();
+        assert!(explicit_sender.local_addr().unwrap().ip().is_loopback());
+
+        assert!(UdpSender::builder(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333))
+            .with_local_addr(SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 0))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn udp_sender_error() {
+        let sender = UdpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333)).unwrap();
+        let packet = OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::Blob(vec![0; 70000])]});
+
+        assert!(matches!(sender.send_osc_packet(&packet), Err(OscSenderError::Send(_))));
+    }
+
+    #[test]
+    fn tcp_sender_drop() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0), Framing::Slip).unwrap();
+        let address = sender.local_addr();
+        drop(sender);
+
+        assert!(TcpSender::new(address, Framing::Slip).is_ok());
+    }
+
+    #[test]
+    fn tcp_sender_stalled_client() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0), Framing::Slip).unwrap();
+        let _client = TcpStream::connect(sender.local_addr()).unwrap();
+        let start = Instant::now();
+
+        while sender.client_count() == 0 {
+            assert!(start.elapsed() < Duration::from_secs(1), "client not accepted");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        // The client never reads, filling the socket buffers until the write times out
+        let packet = OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::Blob(vec![0; 1 << 20])]});
+
+        while sender.client_count() > 0 {
+            assert!(start.elapsed() < Duration::from_secs(10), "stalled client not dropped");
+            sender.send_osc_packet(&packet).unwrap();
+        }
+    }
+
+    #[cfg(feature = "websocket")]
+    #[test]
+    fn websocket_sender_drop() {
+        let sender = WebSocketSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let address = sender.local_addr();
+        drop(sender);
+
+        assert!(WebSocketSender::new(address).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_stream_sender_drop() {
+        let path = std::env::temp_dir().join(format!("tuio-rs-sender-drop-{}.sock", std::process::id()));
+        let sender = UnixStreamSender::new(&path, Framing::Slip).unwrap();
+        let _client = UnixStream::connect(&path).unwrap();
+        let start = Instant::now();
+
+        while sender.client_count() == 0 {
+            assert!(start.elapsed() < Duration::from_secs(1), "client not accepted");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        // The client never reads, filling the socket buffer until the write times out
+        let packet = OscPacket::Message(OscMessage {addr: "/tuio/2Dcur".into(), args: vec![OscType::Blob(vec![0; 1 << 20])]});
+
+        while sender.client_count() > 0 {
+            assert!(start.elapsed() < Duration::from_secs(10), "stalled client not dropped");
+            sender.send_osc_packet(&packet).unwrap();
+        }
+
+        drop(sender);
+        assert!(!path.exists());
+        assert!(UnixStreamSender::new(&path, Framing::Slip).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Returns the session ids of the alive message and the number of set messages of a TUIO 1.1 bundle
+    fn alive_and_set_count(packet: &OscPacket) -> (Vec<i32>, usize) {
+        let OscPacket::Bundle(bundle) = packet else {
+            panic!("not a bundle");
+        };
+
+        let mut alive = Vec::new();
+        let mut set_count = 0;
+
+        for content in &bundle.content {
+            if let OscPacket::Message(message) = content {
+                match message.args.first() {
+                    Some(OscType::String(command)) if command == "alive" => {
+                        alive = message.args[1..].iter().filter_map(|arg| if let OscType::Int(id) = arg {Some(*id)} else {None}).collect();
+                    },
+                    Some(OscType::String(command)) if command == "set" => set_count += 1,
+                    _ => {},
+                }
+            }
+        }
+
+        (alive, set_count)
+    }
+
+    #[test]
+    fn full_update_25d() {
+        for full_update in [true, false] {
+            let mut server = Server::new("source_name").unwrap();
+            server.full_update = full_update;
+
+            server.init_frame();
+            let object_id = server.create_object_25d(1, 0., 0., 0., 0.);
+            let cursor_id = server.create_cursor_25d(0., 0., 0.);
+            let blob_id = server.create_blob_25d(0., 0., 0., 0., 0.1, 0.1, 0.01);
+            let other_ids = [server.create_object_25d(1, 0., 0., 0., 0.), server.create_cursor_25d(0., 0., 0.), server.create_blob_25d(0., 0., 0., 0., 0.1, 0.1, 0.01)];
+            server.frame_packets();
+
+            server.init_frame();
+            server.update_object_25d(object_id, 1., 1., 1., 0.);
+            server.update_cursor_25d(cursor_id, 1., 1., 1.);
+            server.update_blob_25d(blob_id, 1., 1., 1., 0., 0.1, 0.1, 0.01);
+            let packets = server.frame_packets();
+            assert_eq!(packets.len(), 3);
+
+            for (packet, (updated_id, other_id)) in packets.iter().zip([object_id, cursor_id, blob_id].into_iter().zip(other_ids)) {
+                let (alive, set_count) = alive_and_set_count(packet);
+
+                if full_update {
+                    assert_eq!(alive, [updated_id, other_id]);
+                    assert_eq!(set_count, 2);
+                }
+                else {
+                    assert_eq!(alive, [updated_id, other_id]);
+                    assert_eq!(set_count, 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn full_update_3d() {
+        for full_update in [true, false] {
+            let mut server = Server::new("source_name").unwrap();
+            server.full_update = full_update;
+
+            server.init_frame();
+            let object_id = server.create_object_3d(1, Position3D::default(), Angle3D::default());
+            let cursor_id = server.create_cursor_3d(0., 0., 0.);
+            let blob_id = server.create_blob_3d(Position3D::default(), Angle3D::default(), 0.1, 0.1, 0.1, 0.001);
+            let other_ids = [server.create_object_3d(1, Position3D::default(), Angle3D::default()), server.create_cursor_3d(0., 0., 0.), server.create_blob_3d(Position3D::default(), Angle3D::default(), 0.1, 0.1, 0.1, 0.001)];
+            server.frame_packets();
+
+            let position = Position3D {x: 1., y: 1., z: 1.};
+            server.init_frame();
+            server.update_object_3d(object_id, position.clone(), Angle3D::default());
+            server.update_cursor_3d(cursor_id, 1., 1., 1.);
+            server.update_blob_3d(blob_id, position, Angle3D::default(), 0.1, 0.1, 0.1, 0.001);
+            let packets = server.frame_packets();
+            assert_eq!(packets.len(), 3);
+
+            for (packet, (updated_id, other_id)) in packets.iter().zip([object_id, cursor_id, blob_id].into_iter().zip(other_ids)) {
+                let (alive, set_count) = alive_and_set_count(packet);
+
+                if full_update {
+                    assert_eq!(alive, [updated_id, other_id]);
+                    assert_eq!(set_count, 2);
+                }
+                else {
+                    assert_eq!(alive, [updated_id, other_id]);
+                    assert_eq!(set_count, 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn full_update_2d_and_custom() {
+        for full_update in [true, false] {
+            let mut server = Server::new("source_name").unwrap();
+            server.full_update = full_update;
+
+            server.init_frame();
+            let updated_ids = [server.create_object(1, 0., 0., 0.), server.create_cursor(0., 0.), server.create_blob(0., 0., 0., 0.1, 0.1, 0.01), server.create_custom("sf", vec![CustomValue::Float(0.)]).unwrap()];
+            let other_ids = [server.create_object(1, 0., 0., 0.), server.create_cursor(0., 0.), server.create_blob(0., 0., 0., 0.1, 0.1, 0.01), server.create_custom("sf", vec![CustomValue::Float(0.)]).unwrap()];
+            server.frame_packets();
+
+            server.init_frame();
+            server.update_object(updated_ids[0], 1., 1., 0.);
+            server.update_cursor(updated_ids[1], 1., 1.);
+            server.update_blob(updated_ids[2], 1., 1., 0., 0.1, 0.1, 0.01);
+            server.update_custom(updated_ids[3], vec![CustomValue::Float(1.)]).unwrap();
+            let packets = server.frame_packets();
+            assert_eq!(packets.len(), 4);
+
+            for (packet, (updated_id, other_id)) in packets.iter().zip(updated_ids.into_iter().zip(other_ids)) {
+                let (alive, set_count) = alive_and_set_count(packet);
+                assert_eq!(alive, [updated_id, other_id]);
+                assert_eq!(set_count, if full_update {2} else {1});
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_custom_profile() {
+        let mut server = Server::new("source_name").unwrap();
+
+        server.init_frame();
+        assert!(matches!(server.create_custom("xy", vec![CustomValue::Float(0.), CustomValue::Float(0.)]), Err(CustomProfileError::InvalidFormat(_))));
+        assert!(matches!(server.create_custom("sixy", vec![CustomValue::Int(1), CustomValue::Float(0.)]), Err(CustomProfileError::WrongValueCount(_, 2))));
+        let session_id = server.create_custom("sixy", vec![CustomValue::Int(1), CustomValue::Float(0.), CustomValue::Float(0.)]).unwrap();
+        assert!(matches!(server.update_custom(session_id, vec![CustomValue::Float(1.), CustomValue::Float(0.), CustomValue::Float(0.)]), Err(CustomProfileError::WrongValueType(_, 0))));
+
+        // Only the valid entity is sent, which the decoder accepts
+        let packets = server.frame_packets();
+        assert_eq!(packets.len(), 1);
+
+        let OscPacket::Bundle(bundle) = packets[0].clone() else {
+            panic!("not a bundle");
+        };
+
+        assert!(matches!(OscDecoder::decode_bundle(bundle), Ok(TuioBundle {set: Some(Set::Custom(customs)), ..}) if customs[0].get_values() == [CustomValue::Int(1), CustomValue::Float(0.), CustomValue::Float(0.)]));
+    }
+}
@@ -0,0 +1,190 @@
+//! Translation between TUIO 1.1 and TUIO 2.0
+//!
+//! [Object]s are translated into [Token]s, [Cursor]s into [Pointer]s and [Blob]s into [Bounds], and back.
+//! The frame sequence and the source name are carried across.
+
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use rosc::OscBundle;
+
+use crate::{osc_encode_decode::{TuioBundle, TuioBundleType, Set, EncodeOsc, OscEncoder}, tuio2::{Frame, FrameBundle, Token, Pointer, Bounds}, Object, Cursor, Blob};
+
+/// The alive lists of the TUIO 1.1 profiles of a source
+#[derive(Default)]
+struct AliveLists {
+    cursors: Vec<i32>,
+    objects: Vec<i32>,
+    blobs: Vec<i32>,
+}
+
+/// Translates decoded TUIO 1.1 [TuioBundle]s into TUIO 2 [FrameBundle]s
+///
+/// TUIO 1.1 sends one bundle per profile, each with its own alive list, while a TUIO 2 frame has a single alive list.
+/// The translator keeps the last alive list of each profile per source to build complete TUIO 2 alive lists.
+#[derive(Default)]
+pub struct Tuio1To2Translator {
+    alive_lists: IndexMap<String, AliveLists>,
+}
+
+impl Tuio1To2Translator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Translates a [TuioBundle] into a [FrameBundle]
+    ///
+    /// Returns [None] for the profiles without TUIO 2 equivalent, such as 2.5D, 3D and custom profiles
+    /// # Arguments
+    /// * `bundle` - the decoded [TuioBundle]
+    pub fn translate(&mut self, bundle: TuioBundle) -> Option<FrameBundle> {
+        let mut frame_bundle = FrameBundle::new(Frame::new(bundle.fseq, 0, 0, &bundle.source));
+        let alive_lists = self.alive_lists.entry(bundle.source).or_default();
+
+        match bundle.tuio_type {
+            TuioBundleType::Cursor => {
+                alive_lists.cursors = bundle.alive;
+
+                if let Some(Set::Cursor(cursors)) = bundle.set {
+                    frame_bundle.pointers = cursors.into_iter().map(Pointer::from).collect();
+                }
+            },
+            TuioBundleType::Object => {
+                alive_lists.objects = bundle.alive;
+
+                if let Some(Set::Object(objects)) = bundle.set {
+                    frame_bundle.tokens = objects.into_iter().map(Token::from).collect();
+                }
+            },
+            TuioBundleType::Blob => {
+                alive_lists.blobs = bundle.alive;
+
+                if let Some(Set::Blob(blobs)) = bundle.set {
+                    frame_bundle.bounds = blobs.into_iter().map(Bounds::from).collect();
+                }
+            },
+            _ => return None,
+        }
+
+        frame_bundle.alive = alive_lists.cursors.iter()
+            .chain(&alive_lists.objects)
+            .chain(&alive_lists.blobs)
+            .copied()
+            .collect();
+
+        Some(frame_bundle)
+    }
+}
+
+/// The TUIO 1.1 entities of a source
+#[derive(Default)]
+struct Tuio1Collection {
+    cursors: IndexMap<i32, Cursor>,
+    objects: IndexMap<i32, Object>,
+    blobs: IndexMap<i32, Blob>,
+}
+
+/// Translates TUIO 2 [FrameBundle]s into encoded TUIO 1.1 bundles for legacy consumers
+///
+/// A TUIO 2 frame only carries its changed components while a TUIO 1.1 bundle sets its whole profile,
+/// so the translator keeps the entities of each source to encode complete profile bundles.
+#[derive(Default)]
+pub struct Tuio2To1Translator {
+    collections: IndexMap<String, Tuio1Collection>,
+}
+
+impl Tuio2To1Translator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Translates a [FrameBundle] into `/tuio/2Dcur`, `/tuio/2Dobj` and `/tuio/2Dblb` bundles
+    ///
+    /// Returns a bundle for each profile with new, updated or removed entities
+    /// # Arguments
+    /// * `frame_bundle` - the decoded [FrameBundle]
+    pub fn translate(&mut self, frame_bundle: FrameBundle) -> Vec<OscBundle> {
+        let source_name = frame_bundle.frame.source;
+        let fseq = frame_bundle.frame.frame_id;
+        let alive: HashSet<i32> = HashSet::from_iter(frame_bundle.alive);
+        let collection = self.collections.entry(source_name.clone()).or_default();
+        let mut bundles = Vec::new();
+
+        let cursor_count = collection.cursors.len();
+        collection.cursors.retain(|session_id, _| alive.contains(session_id));
+
+        if collection.cursors.len() != cursor_count || !frame_bundle.pointers.is_empty() {
+            collection.cursors.extend(frame_bundle.pointers.into_iter().map(|pointer| (pointer.session_id, Cursor::from(pointer))));
+            bundles.push(OscEncoder::encode_cursor_bundle(collection.cursors.values(), source_name.clone(), fseq));
+        }
+
+        let object_count = collection.objects.len();
+        collection.objects.retain(|session_id, _| alive.contains(session_id));
+
+        if collection.objects.len() != object_count || !frame_bundle.tokens.is_empty() {
+            collection.objects.extend(frame_bundle.tokens.into_iter().map(|token| (token.session_id, Object::from(token))));
+            bundles.push(OscEncoder::encode_object_bundle(collection.objects.values(), source_name.clone(), fseq));
+        }
+
+        let blob_count = collection.blobs.len();
+        collection.blobs.retain(|session_id, _| alive.contains(session_id));
+
+        if collection.blobs.len() != blob_count || !frame_bundle.bounds.is_empty() {
+            collection.blobs.extend(frame_bundle.bounds.into_iter().map(|bounds| (bounds.session_id, Blob::from(bounds))));
+            bundles.push(OscEncoder::encode_blob_bundle(collection.blobs.values(), source_name, fseq));
+        }
+
+        bundles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cursor::Position, osc_encode_decode::{OscDecoder, DecodeOsc}};
+
+    use super::*;
+
+    #[test]
+    fn tuio1_to_tuio2() {
+        let mut translator = Tuio1To2Translator::new();
+
+        let object_bundle = OscEncoder::encode_object_bundle(&[Object::new(0, 7, Position {x: 0.1, y: 0.2}, 1.)], "test".into(), 10);
+        let frame_bundle = translator.translate(OscDecoder::decode_bundle(object_bundle).unwrap()).unwrap();
+
+        assert_eq!(frame_bundle.frame.frame_id, 10);
+        assert_eq!(frame_bundle.frame.source, "test");
+        assert_eq!(frame_bundle.tokens[0].get_component_id(), 7);
+        assert_eq!(frame_bundle.alive, vec![0]);
+
+        let cursor_bundle = OscEncoder::encode_cursor_bundle(&[Cursor::new(1, Position {x: 0.3, y: 0.4})], "test".into(), 11);
+        let frame_bundle = translator.translate(OscDecoder::decode_bundle(cursor_bundle).unwrap()).unwrap();
+
+        assert_eq!(frame_bundle.pointers[0].get_x_position(), 0.3);
+        assert_eq!(frame_bundle.alive, vec![1, 0]);
+    }
+
+    #[test]
+    fn tuio2_to_tuio1() {
+        let mut translator = Tuio2To1Translator::new();
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(10, 640, 480, "test"));
+        frame_bundle.pointers = vec![Pointer::new(0, 0, Position {x: 0.1, y: 0.2}, 0., 0., 0.1, 1.)];
+        frame_bundle.bounds = vec![Bounds::new(1, Position {x: 0.5, y: 0.5}, 0., 0.1, 0.1, 0.01)];
+        frame_bundle.alive = vec![0, 1];
+
+        let bundles: Vec<TuioBundle> = translator.translate(frame_bundle).into_iter().map(|bundle| OscDecoder::decode_bundle(bundle).unwrap()).collect();
+
+        assert!(matches!(bundles[..], [
+            TuioBundle {tuio_type: TuioBundleType::Cursor, fseq: 10, ..},
+            TuioBundle {tuio_type: TuioBundleType::Blob, fseq: 10, ..}
+        ]));
+        assert_eq!(bundles[0].source, "test");
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(11, 640, 480, "test"));
+        frame_bundle.alive = vec![1];
+
+        let bundles: Vec<TuioBundle> = translator.translate(frame_bundle).into_iter().map(|bundle| OscDecoder::decode_bundle(bundle).unwrap()).collect();
+
+        assert!(matches!(bundles[..], [TuioBundle {tuio_type: TuioBundleType::Cursor, ref alive, ..}] if alive.is_empty()));
+    }
+}
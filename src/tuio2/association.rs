@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+/// The kind of a TUIO 2 association, one per message address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssociationKind {
+    /// `/tuio2/coa`, a container holding other components in a slot
+    Container,
+    /// `/tuio2/lia`, links from a component to others
+    Link,
+    /// `/tuio2/lla`, a chain of linked components
+    LinkedList,
+    /// `/tuio2/lta`, a tree of linked components
+    LinkedTree,
+}
+
+/// The kind of a link [Association], which excludes containers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkKind {
+    /// `/tuio2/lia`, links from a component to others
+    Link,
+    /// `/tuio2/lla`, a chain of linked components
+    LinkedList,
+    /// `/tuio2/lta`, a tree of linked components
+    LinkedTree,
+}
+
+impl From<LinkKind> for AssociationKind {
+    fn from(kind: LinkKind) -> Self {
+        match kind {
+            LinkKind::Link => Self::Link,
+            LinkKind::LinkedList => Self::LinkedList,
+            LinkKind::LinkedTree => Self::LinkedTree,
+        }
+    }
+}
+
+/// A link to another component, packed as a link ID of an input and an output port
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Link {
+    pub session_id: i32,
+    pub in_port: u16,
+    pub out_port: u16,
+}
+
+/// A TUIO 2 association between session IDs
+#[derive(Debug, Clone, PartialEq)]
+pub enum Association {
+    /// A container component holding `children` in one of its slots
+    Container {
+        session_id: i32,
+        slot: i32,
+        children: Vec<i32>,
+    },
+    /// Links from a component to others, which can be physical or logical
+    Link {
+        kind: LinkKind,
+        session_id: i32,
+        physical: bool,
+        links: Vec<Link>,
+    },
+}
+
+impl Association {
+    /// Creates a container [Association]
+    /// # Arguments
+    /// * `session_id` - the container's session ID
+    /// * `slot` - the slot holding the children
+    /// * `children` - the session IDs of the contained components
+    pub fn container(session_id: i32, slot: i32, children: Vec<i32>) -> Self {
+        Self::Container { session_id, slot, children }
+    }
+
+    /// Creates a link [Association]
+    /// # Arguments
+    /// * `kind` - the [LinkKind] of the association
+    /// * `session_id` - the session ID of the first component
+    /// * `physical` - whether the components are physically linked
+    /// * `links` - the [Link]s to the other components, in order
+    pub fn link(kind: LinkKind, session_id: i32, physical: bool, links: Vec<Link>) -> Self {
+        Self::Link { kind, session_id, physical, links }
+    }
+
+    pub fn get_kind(&self) -> AssociationKind {
+        match self {
+            Self::Container { .. } => AssociationKind::Container,
+            Self::Link { kind, .. } => (*kind).into(),
+        }
+    }
+
+    /// Returns the session ID of the container or of the first linked component
+    pub fn get_session_id(&self) -> i32 {
+        match self {
+            Self::Container { session_id, .. } | Self::Link { session_id, .. } => *session_id,
+        }
+    }
+
+    /// Returns the session IDs of the contained or linked components
+    pub fn get_children(&self) -> Vec<i32> {
+        match self {
+            Self::Container { children, .. } => children.clone(),
+            Self::Link { links, .. } => links.iter().map(|link| link.session_id).collect(),
+        }
+    }
+}
+
+/// The associations of a source, keyed by their [AssociationKind] and session ID
+#[derive(Debug, Clone, Default)]
+pub struct AssociationGraph {
+    associations: IndexMap<(AssociationKind, i32), Association>,
+}
+
+impl AssociationGraph {
+    pub fn get_associations(&self) -> impl Iterator<Item = &Association> {
+        self.associations.values()
+    }
+
+    pub fn get_association(&self, kind: AssociationKind, session_id: i32) -> Option<&Association> {
+        self.associations.get(&(kind, session_id))
+    }
+
+    /// Returns the session IDs contained in or linked from a component
+    /// # Arguments
+    /// * `session_id` - the component's session ID
+    pub fn get_children(&self, session_id: i32) -> Vec<i32> {
+        self.associations.values()
+            .filter(|association| association.get_session_id() == session_id)
+            .flat_map(Association::get_children)
+            .collect()
+    }
+
+    /// Returns the session IDs of the components containing or linking to a component
+    /// # Arguments
+    /// * `session_id` - the component's session ID
+    pub fn get_parents(&self, session_id: i32) -> Vec<i32> {
+        self.associations.values()
+            .filter(|association| association.get_children().contains(&session_id))
+            .map(Association::get_session_id)
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.associations.is_empty()
+    }
+
+    /// Inserts an [Association], returning the one it replaces
+    pub(crate) fn insert(&mut self, association: Association) -> Option<Association> {
+        self.associations.insert((association.get_kind(), association.get_session_id()), association)
+    }
+
+    /// Removes the associations whose session ID is not contained in a [HashSet], returning them
+    pub(crate) fn retain(&mut self, to_keep: &HashSet<i32>) -> Vec<Association> {
+        let mut removed = Vec::new();
+
+        self.associations.retain(|(_, session_id), association| {
+            if to_keep.contains(session_id) {
+                true
+            } else {
+                removed.push(association.clone());
+                false
+            }
+        });
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::tuio2::{Association, AssociationGraph, Link, LinkKind};
+
+    #[test]
+    fn association_graph() {
+        let mut graph = AssociationGraph::default();
+        graph.insert(Association::container(0, 0, vec![1, 2]));
+        graph.insert(Association::link(LinkKind::LinkedList, 3, true, vec![Link { session_id: 2, in_port: 0, out_port: 1 }]));
+
+        assert_eq!(graph.get_children(0), vec![1, 2]);
+        assert_eq!(graph.get_parents(2), vec![0, 3]);
+
+        let removed = graph.retain(&HashSet::from([0]));
+
+        assert_eq!(removed.len(), 1);
+        assert_eq!(graph.get_parents(2), vec![0]);
+    }
+}
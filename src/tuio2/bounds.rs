@@ -0,0 +1,226 @@
+use std::{f32::consts::PI, time::Duration};
+
+use crate::{blob::Blob, cursor::{Position, Velocity}};
+
+/// TUIO 2 bounds, the equivalent of a TUIO 1.1 [Blob](crate::Blob)
+#[derive(Debug, Clone, Default)]
+pub struct Bounds {
+    pub(crate) session_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) area: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) rotation_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) rotation_acceleration: f32,
+}
+
+impl Bounds {
+    /// Creates new [Bounds]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `position` - a normalized [Position]
+    /// * `angle` - an angle in radians
+    /// * `width` - a normalized width
+    /// * `height` - a normalized height
+    /// * `area` - a normalized area
+    pub fn new(session_id: i32, position: Position, angle: f32, width: f32, height: f32, area: f32) -> Self {
+        Self {
+            session_id,
+            position,
+            angle,
+            width,
+            height,
+            area,
+            velocity: Velocity::default(),
+            rotation_speed: 0f32,
+            acceleration: 0f32,
+            rotation_acceleration: 0f32,
+        }
+    }
+
+    /// Returns these [Bounds] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `rotation_speed` - a rotation speed in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a roation acceleration in radians turn per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        rotation_speed: f32,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the rotation speed in turn per seconds
+    pub fn get_rotation_speed(&self) -> f32 {
+        self.rotation_speed
+    }
+
+    /// Returns the rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Returns the normalized width
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the normalized height
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns the normalized area
+    pub fn get_area(&self) -> f32 {
+        self.area
+    }
+
+    /// Updates the [Bounds], computing its velocity, acceleration, rotation speed and rotation acceleration
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `area` - the new area
+    pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32, width: f32, height: f32, area: f32) {
+        let delta_time = delta_time.as_secs_f32();
+
+        let distance = position.distance_from(&self.position);
+        let last_speed = self.velocity.get_speed();
+        let speed = distance / delta_time;
+
+        self.velocity = Velocity {
+            x: (position.x - self.position.x) / delta_time,
+            y: (position.y - self.position.y) / delta_time,
+        };
+
+        self.acceleration = (speed - last_speed) / delta_time;
+        self.position = position;
+
+        let delta_turn = (angle - self.angle) / (2. * PI);
+        let rotation_speed = delta_turn / delta_time;
+
+        self.rotation_acceleration = (rotation_speed - self.rotation_speed) / delta_time;
+        self.rotation_speed = rotation_speed;
+        self.angle = angle;
+
+        self.width = width;
+        self.height = height;
+        self.area = area;
+    }
+}
+
+impl PartialEq for Bounds {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.width == other.width
+            && self.height == other.height
+            && self.area == other.area
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+    }
+}
+
+impl From<Blob> for Bounds {
+    /// Converts a TUIO 1.1 [Blob] into its TUIO 2 equivalent
+    fn from(blob: Blob) -> Self {
+        Self {
+            session_id: blob.session_id,
+            position: blob.position,
+            angle: blob.angle,
+            width: blob.width,
+            height: blob.height,
+            area: blob.area,
+            velocity: blob.velocity,
+            rotation_speed: blob.rotation_speed,
+            acceleration: blob.acceleration,
+            rotation_acceleration: blob.rotation_acceleration,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor::Position, tuio2::Bounds};
+
+    #[test]
+    fn bounds_update() {
+        let mut bounds = Bounds::new(0, Position { x: 0., y: 0. }, 0., 0., 0., 0.);
+
+        bounds.update(
+            Duration::from_secs(1),
+            Position { x: 1., y: 1. },
+            90f32.to_radians(),
+            0.5,
+            0.5,
+            0.25,
+        );
+
+        assert_eq!(bounds.get_x_position(), 1.);
+        assert_eq!(bounds.get_y_position(), 1.);
+        assert_eq!(bounds.get_x_velocity(), 1.);
+        assert_eq!(bounds.get_y_velocity(), 1.);
+        assert_eq!(bounds.get_acceleration(), SQRT_2);
+        assert_eq!(bounds.get_rotation_speed(), 0.25);
+        assert_eq!(bounds.get_rotation_acceleration(), 0.25);
+        assert_eq!(bounds.get_width(), 0.5);
+        assert_eq!(bounds.get_height(), 0.5);
+        assert_eq!(bounds.get_area(), 0.25);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::cursor::Position;
+
+/// A horizontal span of an area geometry
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Span {
+    /// The normalized position of the span's start
+    pub position: Position,
+    /// The normalized width of the span
+    pub width: f32,
+}
+
+/// Raw sensor data of a component, such as a cropped image
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawData {
+    /// The normalized width of one sample
+    pub width: f32,
+    pub data: Vec<u8>,
+}
+
+/// The geometry attached to a TUIO 2 session ID
+///
+/// Each geometry message only updates its own field, so a [Geometry] keeps the latest
+/// convex hull, contours, skeleton, area and raw data received for its session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Geometry {
+    pub(crate) session_id: i32,
+    pub(crate) convex_hull: Option<Vec<Position>>,
+    pub(crate) outer_contour: Option<Vec<Position>>,
+    pub(crate) inner_contours: Option<Vec<Vec<Position>>>,
+    pub(crate) skeleton: Option<Vec<Vec<Position>>>,
+    pub(crate) skeleton_volume: Option<Vec<f32>>,
+    pub(crate) area: Option<Vec<Span>>,
+    pub(crate) raw: Option<RawData>,
+}
+
+impl Geometry {
+    /// Creates an empty [Geometry]
+    /// # Arguments
+    /// * `session_id` - the session ID of the component it belongs to
+    pub fn new(session_id: i32) -> Self {
+        Self {
+            session_id,
+            ..Default::default()
+        }
+    }
+
+    /// Returns this [Geometry] with a convex hull (`/tuio2/chg`)
+    pub fn with_convex_hull(mut self, points: Vec<Position>) -> Self {
+        self.convex_hull = Some(points);
+        self
+    }
+
+    /// Returns this [Geometry] with an outer contour (`/tuio2/ocg`)
+    pub fn with_outer_contour(mut self, points: Vec<Position>) -> Self {
+        self.outer_contour = Some(points);
+        self
+    }
+
+    /// Returns this [Geometry] with inner contours (`/tuio2/icg`)
+    ///
+    /// A single empty contour is sent as no contour, the received [Geometry] holding an empty list
+    pub fn with_inner_contours(mut self, contours: Vec<Vec<Position>>) -> Self {
+        self.inner_contours = Some(contours);
+        self
+    }
+
+    /// Returns this [Geometry] with a skeleton made of branches (`/tuio2/skg`)
+    ///
+    /// A single empty branch is sent as no branch, the received [Geometry] holding an empty list
+    pub fn with_skeleton(mut self, branches: Vec<Vec<Position>>) -> Self {
+        self.skeleton = Some(branches);
+        self
+    }
+
+    /// Returns this [Geometry] with a radius for each skeleton node (`/tuio2/svg`)
+    pub fn with_skeleton_volume(mut self, radii: Vec<f32>) -> Self {
+        self.skeleton_volume = Some(radii);
+        self
+    }
+
+    /// Returns this [Geometry] with an area made of [Span]s (`/tuio2/ary`)
+    pub fn with_area(mut self, spans: Vec<Span>) -> Self {
+        self.area = Some(spans);
+        self
+    }
+
+    /// Returns this [Geometry] with [RawData] (`/tuio2/raw`)
+    pub fn with_raw(mut self, raw: RawData) -> Self {
+        self.raw = Some(raw);
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_convex_hull(&self) -> Option<&[Position]> {
+        self.convex_hull.as_deref()
+    }
+
+    pub fn get_outer_contour(&self) -> Option<&[Position]> {
+        self.outer_contour.as_deref()
+    }
+
+    pub fn get_inner_contours(&self) -> Option<&[Vec<Position>]> {
+        self.inner_contours.as_deref()
+    }
+
+    pub fn get_skeleton(&self) -> Option<&[Vec<Position>]> {
+        self.skeleton.as_deref()
+    }
+
+    pub fn get_skeleton_volume(&self) -> Option<&[f32]> {
+        self.skeleton_volume.as_deref()
+    }
+
+    pub fn get_area(&self) -> Option<&[Span]> {
+        self.area.as_deref()
+    }
+
+    pub fn get_raw(&self) -> Option<&RawData> {
+        self.raw.as_ref()
+    }
+
+    /// Updates the [Geometry] with the fields present in another one
+    /// # Arguments
+    /// * `geometry` - the received [Geometry]
+    pub fn merge(&mut self, geometry: Geometry) {
+        if geometry.convex_hull.is_some() {
+            self.convex_hull = geometry.convex_hull;
+        }
+
+        if geometry.outer_contour.is_some() {
+            self.outer_contour = geometry.outer_contour;
+        }
+
+        if geometry.inner_contours.is_some() {
+            self.inner_contours = geometry.inner_contours;
+        }
+
+        if geometry.skeleton.is_some() {
+            self.skeleton = geometry.skeleton;
+        }
+
+        if geometry.skeleton_volume.is_some() {
+            self.skeleton_volume = geometry.skeleton_volume;
+        }
+
+        if geometry.area.is_some() {
+            self.area = geometry.area;
+        }
+
+        if geometry.raw.is_some() {
+            self.raw = geometry.raw;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cursor::Position, tuio2::Geometry};
+
+    #[test]
+    fn geometry_merge() {
+        let hull = vec![Position { x: 0., y: 0. }, Position { x: 1., y: 0. }, Position { x: 0., y: 1. }];
+        let mut geometry = Geometry::new(0).with_convex_hull(hull.clone()).with_skeleton_volume(vec![0.1]);
+
+        geometry.merge(Geometry::new(0).with_skeleton_volume(vec![0.2, 0.3]));
+
+        assert_eq!(geometry.get_convex_hull(), Some(&hull[..]));
+        assert_eq!(geometry.get_skeleton_volume(), Some(&[0.2, 0.3][..]));
+        assert_eq!(geometry.get_outer_contour(), None);
+    }
+}
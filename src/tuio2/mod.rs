@@ -0,0 +1,20 @@
+//! TUIO 2.0 components and their OSC encoding
+//!
+//! Unlike TUIO 1.1, a TUIO 2.0 bundle carries every component type of a frame:
+//! a `/tuio2/frm` message, the component messages and a single `/tuio2/alv` message.
+
+mod token;
+mod pointer;
+mod bounds;
+mod symbol;
+mod geometry;
+mod association;
+pub mod osc_encode_decode;
+
+pub use token::Token;
+pub use pointer::Pointer;
+pub use bounds::Bounds;
+pub use symbol::Symbol;
+pub use geometry::{Geometry, Span, RawData};
+pub use association::{Association, AssociationKind, AssociationGraph, Link, LinkKind};
+pub use osc_encode_decode::{Frame, FrameBundle, EncodeTuio2, DecodeTuio2, Tuio2Encoder, Tuio2Decoder};
@@ -0,0 +1,684 @@
+use std::time::SystemTime;
+
+use indexmap::IndexMap;
+
+use rosc::{OscBundle, OscPacket, OscMessage, OscType, OscTime};
+
+use crate::{cursor::{Position, Velocity}, errors::TuioError, tuio2::{Token, Pointer, Bounds, Symbol, Geometry, Span, RawData, Association, LinkKind, Link}};
+
+/// The content of a `/tuio2/frm` message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub frame_id: i32,
+    pub time: OscTime,
+    /// The sensor's width in pixels
+    pub width: u16,
+    /// The sensor's height in pixels
+    pub height: u16,
+    /// The source string, formatted as `name:instance@address`
+    pub source: String,
+}
+
+impl Frame {
+    /// Creates a [Frame] timestamped with the current system time
+    /// # Arguments
+    /// * `frame_id` - the frame's id
+    /// * `width` - the sensor's width in pixels
+    /// * `height` - the sensor's height in pixels
+    /// * `source` - the source string
+    pub fn new(frame_id: i32, width: u16, height: u16, source: &str) -> Self {
+        Self {
+            frame_id,
+            time: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"),
+            width,
+            height,
+            source: source.into(),
+        }
+    }
+}
+
+/// A struct containing informations of a TUIO 2 frame bundle
+#[derive(Debug, Clone)]
+pub struct FrameBundle {
+    pub frame: Frame,
+    pub alive: Vec<i32>,
+    pub tokens: Vec<Token>,
+    pub pointers: Vec<Pointer>,
+    pub bounds: Vec<Bounds>,
+    pub symbols: Vec<Symbol>,
+    /// The geometries received in this frame, one per session ID
+    pub geometries: Vec<Geometry>,
+    /// The container and link associations of this frame
+    pub associations: Vec<Association>,
+    /// The session IDs of the `/tuio2/ala` message, listing the components that still have associations
+    pub alive_associations: Option<Vec<i32>>,
+}
+
+impl FrameBundle {
+    /// Creates an empty [FrameBundle]
+    /// # Arguments
+    /// * `frame` - the bundle's [Frame]
+    pub fn new(frame: Frame) -> Self {
+        Self {
+            frame,
+            alive: Vec::new(),
+            tokens: Vec::new(),
+            pointers: Vec::new(),
+            bounds: Vec::new(),
+            symbols: Vec::new(),
+            geometries: Vec::new(),
+            associations: Vec::new(),
+            alive_associations: None,
+        }
+    }
+}
+
+/// Base trait to implement a TUIO 2 encoder
+pub trait EncodeTuio2<T> {
+    /// Encodes a [FrameBundle] into an OSC bundle
+    /// # Arguments
+    /// * `frame_bundle` - the [FrameBundle] to encode
+    fn encode_frame_bundle(frame_bundle: &FrameBundle) -> T;
+}
+
+/// Base trait to implement a TUIO 2 decoder
+pub trait DecodeTuio2<T> {
+    fn decode_bundle(bundle: T) -> Result<FrameBundle, TuioError>;
+}
+
+/// An implementation of trait [EncodeTuio2] based on [rosc]
+pub struct Tuio2Encoder;
+
+/// An implementation of trait [DecodeTuio2] based on [rosc]
+pub struct Tuio2Decoder;
+
+/// Packs two 16 bits values into an int32, as done for the type/user id and the sensor dimension
+fn pack(high: u16, low: u16) -> i32 {
+    ((high as u32) << 16 | low as u32) as i32
+}
+
+/// Unpacks an int32 into two 16 bits values
+fn unpack(value: i32) -> (u16, u16) {
+    ((value as u32 >> 16) as u16, value as u16)
+}
+
+/// Flattens points into x/y float pairs
+fn encode_points(points: &[Position]) -> impl Iterator<Item = OscType> + '_ {
+    points.iter().flat_map(|point| [OscType::Float(point.x), OscType::Float(point.y)])
+}
+
+/// Flattens groups of points, separating each group with a `true` argument
+///
+/// A single empty group has no argument, as no group at all, and is decoded as no group
+fn encode_point_groups(groups: &[Vec<Position>]) -> Vec<OscType> {
+    let mut args = Vec::new();
+
+    for (index, group) in groups.iter().enumerate() {
+        if index > 0 {
+            args.push(OscType::Bool(true));
+        }
+
+        args.extend(encode_points(group));
+    }
+
+    args
+}
+
+/// Encodes the geometry messages of a [Geometry], one per present field
+fn encode_geometry(geometry: &Geometry) -> Vec<OscPacket> {
+    let session_id = OscType::Int(geometry.get_session_id());
+    let mut messages = Vec::new();
+
+    if let Some(points) = geometry.get_convex_hull() {
+        messages.push(message("/tuio2/chg", std::iter::once(session_id.clone()).chain(encode_points(points)).collect()));
+    }
+
+    if let Some(points) = geometry.get_outer_contour() {
+        messages.push(message("/tuio2/ocg", std::iter::once(session_id.clone()).chain(encode_points(points)).collect()));
+    }
+
+    if let Some(contours) = geometry.get_inner_contours() {
+        messages.push(message("/tuio2/icg", std::iter::once(session_id.clone()).chain(encode_point_groups(contours)).collect()));
+    }
+
+    if let Some(branches) = geometry.get_skeleton() {
+        messages.push(message("/tuio2/skg", std::iter::once(session_id.clone()).chain(encode_point_groups(branches)).collect()));
+    }
+
+    if let Some(radii) = geometry.get_skeleton_volume() {
+        messages.push(message("/tuio2/svg", std::iter::once(session_id.clone()).chain(radii.iter().map(|radius| OscType::Float(*radius))).collect()));
+    }
+
+    if let Some(spans) = geometry.get_area() {
+        messages.push(message("/tuio2/ary", std::iter::once(session_id.clone()).chain(spans.iter().flat_map(|span| [
+            OscType::Float(span.position.x),
+            OscType::Float(span.position.y),
+            OscType::Float(span.width)
+        ])).collect()));
+    }
+
+    if let Some(raw) = geometry.get_raw() {
+        messages.push(message("/tuio2/raw", vec![session_id, OscType::Float(raw.width), OscType::Blob(raw.data.clone())]));
+    }
+
+    messages
+}
+
+/// Encodes a `/tuio2/coa`, `/tuio2/lia`, `/tuio2/lla` or `/tuio2/lta` message
+fn encode_association(association: &Association) -> OscPacket {
+    match association {
+        Association::Container { session_id, slot, children } => message("/tuio2/coa", [*session_id, *slot].iter()
+            .chain(children)
+            .map(|id| OscType::Int(*id))
+            .collect()),
+        Association::Link { kind, session_id, physical, links } => {
+            let address = match kind {
+                LinkKind::Link => "/tuio2/lia",
+                LinkKind::LinkedList => "/tuio2/lla",
+                LinkKind::LinkedTree => "/tuio2/lta",
+            };
+
+            message(address, [OscType::Int(*session_id), OscType::Bool(*physical)].into_iter()
+                .chain(links.iter().flat_map(|link| [OscType::Int(link.session_id), OscType::Int(pack(link.in_port, link.out_port))]))
+                .collect())
+        }
+    }
+}
+
+fn message(address: &str, args: Vec<OscType>) -> OscPacket {
+    OscPacket::Message(OscMessage {
+        addr: address.into(),
+        args
+    })
+}
+
+impl EncodeTuio2<OscBundle> for Tuio2Encoder {
+    fn encode_frame_bundle(frame_bundle: &FrameBundle) -> OscBundle {
+        let frame = &frame_bundle.frame;
+        let mut content = vec![message("/tuio2/frm", vec![
+            OscType::Int(frame.frame_id),
+            OscType::Time(frame.time),
+            OscType::Int(pack(frame.width, frame.height)),
+            OscType::String(frame.source.clone())
+        ])];
+
+        content.extend(frame_bundle.tokens.iter().map(|token| message("/tuio2/tok", vec![
+            OscType::Int(token.get_session_id()),
+            OscType::Int(pack(token.get_type_id(), token.get_user_id())),
+            OscType::Int(token.get_component_id()),
+            OscType::Float(token.get_x_position()),
+            OscType::Float(token.get_y_position()),
+            OscType::Float(token.get_angle()),
+            OscType::Float(token.get_x_velocity()),
+            OscType::Float(token.get_y_velocity()),
+            OscType::Float(token.get_rotation_speed()),
+            OscType::Float(token.get_acceleration()),
+            OscType::Float(token.get_rotation_acceleration())
+        ])));
+
+        content.extend(frame_bundle.pointers.iter().map(|pointer| message("/tuio2/ptr", vec![
+            OscType::Int(pointer.get_session_id()),
+            OscType::Int(pack(pointer.get_type_id(), pointer.get_user_id())),
+            OscType::Int(pointer.get_component_id()),
+            OscType::Float(pointer.get_x_position()),
+            OscType::Float(pointer.get_y_position()),
+            OscType::Float(pointer.get_angle()),
+            OscType::Float(pointer.get_shear()),
+            OscType::Float(pointer.get_radius()),
+            OscType::Float(pointer.get_pressure()),
+            OscType::Float(pointer.get_x_velocity()),
+            OscType::Float(pointer.get_y_velocity()),
+            OscType::Float(pointer.get_pressure_speed()),
+            OscType::Float(pointer.get_acceleration()),
+            OscType::Float(pointer.get_pressure_acceleration())
+        ])));
+
+        content.extend(frame_bundle.bounds.iter().map(|bounds| message("/tuio2/bnd", vec![
+            OscType::Int(bounds.get_session_id()),
+            OscType::Float(bounds.get_x_position()),
+            OscType::Float(bounds.get_y_position()),
+            OscType::Float(bounds.get_angle()),
+            OscType::Float(bounds.get_width()),
+            OscType::Float(bounds.get_height()),
+            OscType::Float(bounds.get_area()),
+            OscType::Float(bounds.get_x_velocity()),
+            OscType::Float(bounds.get_y_velocity()),
+            OscType::Float(bounds.get_rotation_speed()),
+            OscType::Float(bounds.get_acceleration()),
+            OscType::Float(bounds.get_rotation_acceleration())
+        ])));
+
+        content.extend(frame_bundle.symbols.iter().map(|symbol| message("/tuio2/sym", vec![
+            OscType::Int(symbol.get_session_id()),
+            OscType::Int(pack(symbol.get_type_id(), symbol.get_user_id())),
+            OscType::Int(symbol.get_component_id()),
+            OscType::String(symbol.get_group().into()),
+            OscType::String(symbol.get_data().into())
+        ])));
+
+        content.extend(frame_bundle.geometries.iter().flat_map(encode_geometry));
+
+        content.extend(frame_bundle.associations.iter().map(encode_association));
+
+        if let Some(alive_associations) = &frame_bundle.alive_associations {
+            content.push(message("/tuio2/ala", alive_associations.iter().map(|id| OscType::Int(*id)).collect()));
+        }
+
+        content.push(message("/tuio2/alv", frame_bundle.alive.iter().map(|id| OscType::Int(*id)).collect()));
+
+        OscBundle {
+            timetag: frame.time,
+            content
+        }
+    }
+}
+
+fn try_unwrap_frame_args(args: &[OscType]) -> Result<Frame, u8> {
+    let (width, height) = unpack(args[2].clone().int().ok_or(2)?);
+
+    Ok(Frame {
+        frame_id: args[0].clone().int().ok_or(0)?,
+        time: args[1].clone().time().ok_or(1)?,
+        width,
+        height,
+        source: args[3].clone().string().ok_or(3)?,
+    })
+}
+
+fn try_unwrap_token_args(args: &[OscType]) -> Result<Token, u8> {
+    let (type_id, user_id) = unpack(args[1].clone().int().ok_or(1)?);
+    let token = Token {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id,
+        user_id,
+        component_id: args[2].clone().int().ok_or(2)?,
+        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        ..Default::default()
+    };
+
+    if args.len() == 6 {
+        return Ok(token);
+    }
+
+    Ok(token.with_motion(
+        Velocity {x: args[6].clone().float().ok_or(6)?, y: args[7].clone().float().ok_or(7)?},
+        args[8].clone().float().ok_or(8)?,
+        args[9].clone().float().ok_or(9)?,
+        args[10].clone().float().ok_or(10)?,
+    ))
+}
+
+fn try_unwrap_pointer_args(args: &[OscType]) -> Result<Pointer, u8> {
+    let (type_id, user_id) = unpack(args[1].clone().int().ok_or(1)?);
+    let pointer = Pointer {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id,
+        user_id,
+        component_id: args[2].clone().int().ok_or(2)?,
+        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        shear: args[6].clone().float().ok_or(6)?,
+        radius: args[7].clone().float().ok_or(7)?,
+        pressure: args[8].clone().float().ok_or(8)?,
+        ..Default::default()
+    };
+
+    if args.len() == 9 {
+        return Ok(pointer);
+    }
+
+    Ok(pointer.with_motion(
+        Velocity {x: args[9].clone().float().ok_or(9)?, y: args[10].clone().float().ok_or(10)?},
+        args[11].clone().float().ok_or(11)?,
+        args[12].clone().float().ok_or(12)?,
+        args[13].clone().float().ok_or(13)?,
+    ))
+}
+
+fn try_unwrap_bounds_args(args: &[OscType]) -> Result<Bounds, u8> {
+    let bounds = Bounds {
+        session_id: args[0].clone().int().ok_or(0)?,
+        position: Position {x: args[1].clone().float().ok_or(1)?, y: args[2].clone().float().ok_or(2)?},
+        angle: args[3].clone().float().ok_or(3)?,
+        width: args[4].clone().float().ok_or(4)?,
+        height: args[5].clone().float().ok_or(5)?,
+        area: args[6].clone().float().ok_or(6)?,
+        ..Default::default()
+    };
+
+    if args.len() == 7 {
+        return Ok(bounds);
+    }
+
+    Ok(bounds.with_motion(
+        Velocity {x: args[7].clone().float().ok_or(7)?, y: args[8].clone().float().ok_or(8)?},
+        args[9].clone().float().ok_or(9)?,
+        args[10].clone().float().ok_or(10)?,
+        args[11].clone().float().ok_or(11)?,
+    ))
+}
+
+fn try_unwrap_symbol_args(args: &[OscType]) -> Result<Symbol, u8> {
+    let (type_id, user_id) = unpack(args[1].clone().int().ok_or(1)?);
+
+    Ok(Symbol {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id,
+        user_id,
+        component_id: args[2].clone().int().ok_or(2)?,
+        group: args[3].clone().string().ok_or(3)?,
+        data: args[4].clone().string().ok_or(4)?,
+    })
+}
+
+/// Converts an argument index into the [u8] index of [TuioError::WrongArgumentType], saturating for long geometries
+fn arg_index(index: usize) -> u8 {
+    u8::try_from(index).unwrap_or(u8::MAX)
+}
+
+/// Decodes the x/y float pairs of `args[start..]`
+fn try_unwrap_points(args: &[OscType], start: usize) -> Result<Vec<Position>, u8> {
+    args[start..].chunks(2).enumerate().map(|(pair_index, pair)| {
+        let index = start + pair_index * 2;
+
+        match pair {
+            [x, y] => Ok(Position {x: x.clone().float().ok_or(arg_index(index))?, y: y.clone().float().ok_or(arg_index(index + 1))?}),
+            _ => Err(arg_index(index))
+        }
+    }).collect()
+}
+
+/// Decodes groups of x/y float pairs separated by `true` arguments, starting after the session ID
+///
+/// A message without any argument after the session ID holds no group
+fn try_unwrap_point_groups(args: &[OscType]) -> Result<Vec<Vec<Position>>, u8> {
+    let mut groups = Vec::new();
+
+    if args.len() == 1 {
+        return Ok(groups);
+    }
+    let mut start = 1;
+
+    for (index, arg) in args.iter().enumerate().skip(1) {
+        if let OscType::Bool(true) = arg {
+            groups.push(try_unwrap_points(&args[..index], start)?);
+            start = index + 1;
+        }
+    }
+
+    groups.push(try_unwrap_points(args, start)?);
+    Ok(groups)
+}
+
+fn try_unwrap_geometry_args(address: &str, args: &[OscType]) -> Result<Geometry, u8> {
+    let geometry = Geometry::new(args[0].clone().int().ok_or(0)?);
+
+    Ok(match address {
+        "/tuio2/chg" => geometry.with_convex_hull(try_unwrap_points(args, 1)?),
+        "/tuio2/ocg" => geometry.with_outer_contour(try_unwrap_points(args, 1)?),
+        "/tuio2/icg" => geometry.with_inner_contours(try_unwrap_point_groups(args)?),
+        "/tuio2/skg" => geometry.with_skeleton(try_unwrap_point_groups(args)?),
+        "/tuio2/svg" => geometry.with_skeleton_volume(args.iter().enumerate().skip(1).map(|(index, arg)| arg.clone().float().ok_or(arg_index(index))).collect::<Result<_, _>>()?),
+        "/tuio2/ary" => geometry.with_area(args[1..].chunks(3).enumerate().map(|(span_index, span)| {
+            let index = 1 + span_index * 3;
+
+            match span {
+                [x, y, width] => Ok(Span {
+                    position: Position {x: x.clone().float().ok_or(arg_index(index))?, y: y.clone().float().ok_or(arg_index(index + 1))?},
+                    width: width.clone().float().ok_or(arg_index(index + 2))?
+                }),
+                _ => Err(arg_index(index))
+            }
+        }).collect::<Result<_, _>>()?),
+        _ => geometry.with_raw(RawData {
+            width: args.get(1).and_then(|arg| arg.clone().float()).ok_or(1)?,
+            data: args.get(2).and_then(|arg| arg.clone().blob()).ok_or(2)?
+        }),
+    })
+}
+
+fn try_unwrap_association_args(address: &str, args: &[OscType]) -> Result<Association, u8> {
+    let session_id = args[0].clone().int().ok_or(0)?;
+
+    if address == "/tuio2/coa" {
+        return Ok(Association::Container {
+            session_id,
+            slot: args[1].clone().int().ok_or(1)?,
+            children: args.iter().enumerate().skip(2).map(|(index, arg)| arg.clone().int().ok_or(arg_index(index))).collect::<Result<_, _>>()?,
+        });
+    }
+
+    let kind = match address {
+        "/tuio2/lla" => LinkKind::LinkedList,
+        "/tuio2/lta" => LinkKind::LinkedTree,
+        _ => LinkKind::Link,
+    };
+
+    Ok(Association::Link {
+        kind,
+        session_id,
+        physical: args[1].clone().bool().ok_or(1)?,
+        links: args[2..].chunks(2).enumerate().map(|(link_index, link)| {
+            let index = 2 + link_index * 2;
+
+            match link {
+                [session_id, link_id] => {
+                    let (in_port, out_port) = unpack(link_id.clone().int().ok_or(arg_index(index + 1))?);
+                    Ok(Link {session_id: session_id.clone().int().ok_or(arg_index(index))?, in_port, out_port})
+                },
+                _ => Err(arg_index(index))
+            }
+        }).collect::<Result<_, _>>()?,
+    })
+}
+
+/// Decodes a component message
+///
+/// # Arguments
+/// * `message` - the component [OscMessage]
+/// * `arg_counts` - the accepted numbers of arguments
+/// * `try_unwrap` - a function converting the arguments, returning the index of the first wrong argument on failure
+fn decode_component<T>(message: &OscMessage, arg_counts: &[usize], try_unwrap: impl FnOnce(&[OscType]) -> Result<T, u8>) -> Result<T, TuioError> {
+    if !arg_counts.contains(&message.args.len()) {
+        return Err(TuioError::MissingArguments(message.clone()));
+    }
+
+    try_unwrap(&message.args).map_err(|index| TuioError::WrongArgumentType(message.clone(), index))
+}
+
+impl DecodeTuio2<OscBundle> for Tuio2Decoder {
+    fn decode_bundle(bundle: OscBundle) -> Result<FrameBundle, TuioError> {
+        let mut frame = None;
+        let mut alive = None;
+        let mut tokens = Vec::new();
+        let mut pointers = Vec::new();
+        let mut bounds = Vec::new();
+        let mut symbols = Vec::new();
+        let mut geometries: IndexMap<i32, Geometry> = IndexMap::new();
+        let mut associations = Vec::new();
+        let mut alive_associations = None;
+
+        for packet in &bundle.content {
+            if let OscPacket::Message(message) = packet {
+                match message.addr.as_str() {
+                    "/tuio2/frm" => frame = Some(decode_component(message, &[4], try_unwrap_frame_args)?),
+                    "/tuio2/alv" => alive = Some(message.args.iter().filter_map(|e| e.clone().int()).collect()),
+                    "/tuio2/tok" => tokens.push(decode_component(message, &[6, 11], try_unwrap_token_args)?),
+                    "/tuio2/ptr" => pointers.push(decode_component(message, &[9, 14], try_unwrap_pointer_args)?),
+                    "/tuio2/bnd" => bounds.push(decode_component(message, &[7, 12], try_unwrap_bounds_args)?),
+                    "/tuio2/sym" => symbols.push(decode_component(message, &[5], try_unwrap_symbol_args)?),
+                    address @ ("/tuio2/chg" | "/tuio2/ocg" | "/tuio2/icg" | "/tuio2/skg" | "/tuio2/svg" | "/tuio2/ary" | "/tuio2/raw") => {
+                        if message.args.is_empty() || (address == "/tuio2/raw" && message.args.len() != 3) {
+                            return Err(TuioError::MissingArguments(message.clone()));
+                        }
+
+                        let geometry = try_unwrap_geometry_args(address, &message.args).map_err(|index| TuioError::WrongArgumentType(message.clone(), index))?;
+
+                        match geometries.entry(geometry.get_session_id()) {
+                            indexmap::map::Entry::Occupied(mut entry) => entry.get_mut().merge(geometry),
+                            indexmap::map::Entry::Vacant(entry) => {
+                                entry.insert(geometry);
+                            },
+                        }
+                    },
+                    address @ ("/tuio2/coa" | "/tuio2/lia" | "/tuio2/lla" | "/tuio2/lta") => {
+                        if message.args.len() < 2 {
+                            return Err(TuioError::MissingArguments(message.clone()));
+                        }
+
+                        associations.push(try_unwrap_association_args(address, &message.args).map_err(|index| TuioError::WrongArgumentType(message.clone(), index))?);
+                    },
+                    "/tuio2/ala" => alive_associations = Some(message.args.iter().filter_map(|e| e.clone().int()).collect()),
+                    _ => return Err(TuioError::UnknownAddress(message.clone()))
+                }
+            }
+        }
+
+        match (frame, alive) {
+            (Some(frame), Some(alive)) => Ok(FrameBundle {
+                frame,
+                alive,
+                tokens,
+                pointers,
+                bounds,
+                symbols,
+                geometries: geometries.into_values().collect(),
+                associations,
+                alive_associations,
+            }),
+            _ => Err(TuioError::IncompleteBundle(bundle))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cursor::{Position, Velocity};
+
+    use super::*;
+
+    #[test]
+    fn encoding_decoding() {
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test:0@127.0.0.1"));
+
+        frame_bundle.tokens = vec![Token::new(0, 4, Position {x: 0.1, y: 0.2}, 0.3).with_type_user_id(1, 2), Token::new(1, 5, Position {x: 0.5, y: 0.5}, 0.).with_motion(Velocity {x: 0.1, y: 0.2}, 0.3, 0.4, 0.5)];
+        frame_bundle.pointers = vec![Pointer::new(2, 0, Position {x: 0.1, y: 0.2}, 0., 0., 0.05, 1.).with_type_user_id(3, u16::MAX)];
+        frame_bundle.bounds = vec![Bounds::new(3, Position {x: 0.5, y: 0.5}, 0., 0.5, 0.5, 0.25)];
+        frame_bundle.symbols = vec![Symbol::new(4, 12, "qr/url", "https://www.tuio.org")];
+        frame_bundle.alive = vec![0, 1, 2, 3, 4];
+
+        let decoded_bundle = Tuio2Decoder::decode_bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)).unwrap();
+
+        assert_eq!(decoded_bundle.frame, frame_bundle.frame);
+        assert_eq!(decoded_bundle.alive, frame_bundle.alive);
+        assert_eq!(decoded_bundle.tokens, frame_bundle.tokens);
+        assert_eq!(decoded_bundle.pointers, frame_bundle.pointers);
+        assert_eq!(decoded_bundle.bounds, frame_bundle.bounds);
+        assert_eq!(decoded_bundle.symbols, frame_bundle.symbols);
+    }
+
+    #[test]
+    fn encoding_decoding_geometry() {
+        let square = vec![Position {x: 0., y: 0.}, Position {x: 1., y: 0.}, Position {x: 1., y: 1.}, Position {x: 0., y: 1.}];
+        let triangle = vec![Position {x: 0.2, y: 0.2}, Position {x: 0.4, y: 0.2}, Position {x: 0.3, y: 0.4}];
+
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.bounds = vec![Bounds::new(0, Position {x: 0.5, y: 0.5}, 0., 1., 1., 1.)];
+        frame_bundle.geometries = vec![
+            Geometry::new(0)
+                .with_convex_hull(square.clone())
+                .with_outer_contour(square.clone())
+                .with_inner_contours(vec![triangle.clone(), triangle.clone()])
+                .with_skeleton(vec![square, triangle])
+                .with_skeleton_volume(vec![0.1, 0.2])
+                .with_area(vec![Span {position: Position {x: 0., y: 0.5}, width: 1.}])
+                .with_raw(RawData {width: 0.01, data: vec![0, 127, 255]})
+        ];
+        frame_bundle.alive = vec![0];
+
+        let decoded_bundle = Tuio2Decoder::decode_bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)).unwrap();
+
+        assert_eq!(decoded_bundle.geometries, frame_bundle.geometries);
+    }
+
+    #[test]
+    fn encoding_decoding_empty_point_groups() {
+        let point = vec![Position {x: 0.2, y: 0.2}];
+
+        // Without any point, no group and a single empty group are both sent as no group
+        for (groups, decoded_groups) in [
+            (vec![], vec![]),
+            (vec![vec![]], vec![]),
+            (vec![vec![], point.clone()], vec![vec![], point.clone()]),
+            (vec![point.clone(), vec![]], vec![point.clone(), vec![]]),
+        ] {
+            let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+            frame_bundle.geometries = vec![Geometry::new(0).with_inner_contours(groups.clone()).with_skeleton(groups)];
+            frame_bundle.alive = vec![0];
+
+            let decoded_bundle = Tuio2Decoder::decode_bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)).unwrap();
+            assert_eq!(decoded_bundle.geometries, [Geometry::new(0).with_inner_contours(decoded_groups.clone()).with_skeleton(decoded_groups)]);
+        }
+    }
+
+    #[test]
+    fn encoding_decoding_associations() {
+        let mut frame_bundle = FrameBundle::new(Frame::new(1, 640, 480, "test"));
+        frame_bundle.associations = vec![
+            Association::container(0, 1, vec![1, 2]),
+            Association::link(LinkKind::Link, 3, false, vec![Link {session_id: 4, in_port: 1, out_port: 2}]),
+            Association::link(LinkKind::LinkedList, 4, true, vec![Link {session_id: 5, in_port: 0, out_port: 0}, Link {session_id: 6, in_port: 3, out_port: 4}]),
+            Association::link(LinkKind::LinkedTree, 7, true, vec![]),
+        ];
+        frame_bundle.alive_associations = Some(vec![0, 3, 4, 7]);
+        frame_bundle.alive = vec![0, 1, 2, 3, 4, 5, 6, 7];
+
+        let decoded_bundle = Tuio2Decoder::decode_bundle(Tuio2Encoder::encode_frame_bundle(&frame_bundle)).unwrap();
+
+        assert_eq!(decoded_bundle.associations, frame_bundle.associations);
+        assert_eq!(decoded_bundle.alive_associations, frame_bundle.alive_associations);
+    }
+
+    #[test]
+    fn decoding_odd_geometry() {
+        let bundle = OscBundle {
+            timetag: OscTime::try_from(SystemTime::now()).unwrap(),
+            content: vec![
+                message("/tuio2/frm", vec![OscType::Int(1), OscType::Time(OscTime {seconds: 0, fractional: 0}), OscType::Int(0), OscType::String("test".into())]),
+                message("/tuio2/chg", vec![OscType::Int(0), OscType::Float(0.1), OscType::Float(0.2), OscType::Float(0.3)]),
+                message("/tuio2/alv", vec![OscType::Int(0)])
+            ]
+        };
+
+        assert!(matches!(Tuio2Decoder::decode_bundle(bundle), Err(TuioError::WrongArgumentType(_, 3))));
+    }
+
+    #[test]
+    fn decoding_without_motion() {
+        let bundle = OscBundle {
+            timetag: OscTime::try_from(SystemTime::now()).unwrap(),
+            content: vec![
+                message("/tuio2/frm", vec![OscType::Int(1), OscType::Time(OscTime {seconds: 0, fractional: 0}), OscType::Int(pack(640, 480)), OscType::String("test".into())]),
+                message("/tuio2/tok", vec![OscType::Int(0), OscType::Int(0), OscType::Int(4), OscType::Float(0.1), OscType::Float(0.2), OscType::Float(0.3)]),
+                message("/tuio2/alv", vec![OscType::Int(0)])
+            ]
+        };
+
+        let decoded_bundle = Tuio2Decoder::decode_bundle(bundle).unwrap();
+
+        assert_eq!(decoded_bundle.frame.width, 640);
+        assert_eq!(decoded_bundle.frame.height, 480);
+        assert_eq!(decoded_bundle.tokens, vec![Token::new(0, 4, Position {x: 0.1, y: 0.2}, 0.3)]);
+    }
+
+    #[test]
+    fn decoding_without_frame() {
+        let bundle = OscBundle {
+            timetag: OscTime::try_from(SystemTime::now()).unwrap(),
+            content: vec![message("/tuio2/alv", vec![])]
+        };
+
+        assert!(matches!(Tuio2Decoder::decode_bundle(bundle), Err(TuioError::IncompleteBundle(_))));
+    }
+}
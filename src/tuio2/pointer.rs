@@ -0,0 +1,249 @@
+use std::time::Duration;
+
+use crate::cursor::{Cursor, Position, Velocity};
+
+/// A TUIO 2 pointer, the equivalent of a TUIO 1.1 [Cursor](crate::Cursor)
+#[derive(Debug, Clone, Default)]
+pub struct Pointer {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) component_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) shear: f32,
+    pub(crate) radius: f32,
+    pub(crate) pressure: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) pressure_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) pressure_acceleration: f32,
+}
+
+impl Pointer {
+    /// Creates a new [Pointer]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `component_id` - the pointer's component ID
+    /// * `position` - a normalized [Position]
+    /// * `angle` - an angle in radians
+    /// * `shear` - a shear angle in radians
+    /// * `radius` - a normalized radius
+    /// * `pressure` - a normalized pressure, negative when hovering
+    pub fn new(session_id: i32, component_id: i32, position: Position, angle: f32, shear: f32, radius: f32, pressure: f32) -> Self {
+        Self {
+            session_id,
+            type_id: 0,
+            user_id: 0,
+            component_id,
+            position,
+            angle,
+            shear,
+            radius,
+            pressure,
+            velocity: Velocity::default(),
+            pressure_speed: 0f32,
+            acceleration: 0f32,
+            pressure_acceleration: 0f32,
+        }
+    }
+
+    /// Returns this [Pointer] with a type ID and a user ID
+    /// # Arguments
+    /// * `type_id` - the pointer's type ID
+    /// * `user_id` - the pointer's user ID
+    pub fn with_type_user_id(mut self, type_id: u16, user_id: u16) -> Self {
+        self.type_id = type_id;
+        self.user_id = user_id;
+        self
+    }
+
+    /// Returns this [Pointer] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `pressure_speed` - a pressure speed
+    /// * `acceleration` - a normalized acceleration
+    /// * `pressure_acceleration` - a pressure acceleration
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        pressure_speed: f32,
+        acceleration: f32,
+        pressure_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.pressure_speed = pressure_speed;
+        self.acceleration = acceleration;
+        self.pressure_acceleration = pressure_acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_component_id(&self) -> i32 {
+        self.component_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the shear angle in radians
+    pub fn get_shear(&self) -> f32 {
+        self.shear
+    }
+
+    /// Returns the normalized radius
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Returns the normalized pressure
+    pub fn get_pressure(&self) -> f32 {
+        self.pressure
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_pressure_speed(&self) -> f32 {
+        self.pressure_speed
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    pub fn get_pressure_acceleration(&self) -> f32 {
+        self.pressure_acceleration
+    }
+
+    /// Updates the [Pointer], computing its velocity, acceleration, pressure speed and pressure acceleration
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `shear` - the new shear angle
+    /// * `radius` - the new radius
+    /// * `pressure` - the new pressure
+    pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32, shear: f32, radius: f32, pressure: f32) {
+        let delta_time = delta_time.as_secs_f32();
+
+        let distance = position.distance_from(&self.position);
+        let last_speed = self.velocity.get_speed();
+        let speed = distance / delta_time;
+
+        self.velocity = Velocity {
+            x: (position.x - self.position.x) / delta_time,
+            y: (position.y - self.position.y) / delta_time,
+        };
+
+        self.acceleration = (speed - last_speed) / delta_time;
+        self.position = position;
+
+        let pressure_speed = (pressure - self.pressure) / delta_time;
+
+        self.pressure_acceleration = (pressure_speed - self.pressure_speed) / delta_time;
+        self.pressure_speed = pressure_speed;
+        self.pressure = pressure;
+
+        self.angle = angle;
+        self.shear = shear;
+        self.radius = radius;
+    }
+}
+
+impl PartialEq for Pointer {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.type_id == other.type_id
+            && self.user_id == other.user_id
+            && self.component_id == other.component_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.shear == other.shear
+            && self.radius == other.radius
+            && self.pressure == other.pressure
+            && self.velocity == other.velocity
+            && self.pressure_speed == other.pressure_speed
+            && self.acceleration == other.acceleration
+            && self.pressure_acceleration == other.pressure_acceleration
+    }
+}
+
+impl From<Cursor> for Pointer {
+    /// Converts a TUIO 1.1 [Cursor] into its TUIO 2 equivalent
+    fn from(cursor: Cursor) -> Self {
+        Self {
+            session_id: cursor.session_id,
+            type_id: 0,
+            user_id: 0,
+            component_id: 0,
+            position: cursor.position,
+            angle: 0f32,
+            shear: 0f32,
+            radius: 0f32,
+            pressure: 0f32,
+            velocity: cursor.velocity,
+            pressure_speed: 0f32,
+            acceleration: cursor.acceleration,
+            pressure_acceleration: 0f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor::Position, tuio2::Pointer};
+
+    #[test]
+    fn pointer_update() {
+        let mut pointer = Pointer::new(0, 0, Position { x: 0., y: 0. }, 0., 0., 0.1, 0.);
+
+        pointer.update(Duration::from_secs(1), Position { x: 1., y: 1. }, 0., 0., 0.1, 0.5);
+
+        assert_eq!(pointer.get_x_position(), 1.);
+        assert_eq!(pointer.get_y_position(), 1.);
+        assert_eq!(pointer.get_x_velocity(), 1.);
+        assert_eq!(pointer.get_y_velocity(), 1.);
+        assert_eq!(pointer.get_acceleration(), SQRT_2);
+        assert_eq!(pointer.get_pressure(), 0.5);
+        assert_eq!(pointer.get_pressure_speed(), 0.5);
+        assert_eq!(pointer.get_pressure_acceleration(), 0.5);
+    }
+}
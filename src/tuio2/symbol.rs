@@ -0,0 +1,63 @@
+/// A TUIO 2 symbol, such as a fiducial marker, a QR code or an RFID tag
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Symbol {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) component_id: i32,
+    pub(crate) group: String,
+    pub(crate) data: String,
+}
+
+impl Symbol {
+    /// Creates a new [Symbol]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `component_id` - the symbol's component ID
+    /// * `group` - the symbol's group, such as "fidtrk/18" or "qr/url"
+    /// * `data` - the symbol's encoded data
+    pub fn new(session_id: i32, component_id: i32, group: &str, data: &str) -> Self {
+        Self {
+            session_id,
+            type_id: 0,
+            user_id: 0,
+            component_id,
+            group: group.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Returns this [Symbol] with a type ID and a user ID
+    /// # Arguments
+    /// * `type_id` - the symbol's type ID
+    /// * `user_id` - the symbol's user ID
+    pub fn with_type_user_id(mut self, type_id: u16, user_id: u16) -> Self {
+        self.type_id = type_id;
+        self.user_id = user_id;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_component_id(&self) -> i32 {
+        self.component_id
+    }
+
+    pub fn get_group(&self) -> &str {
+        &self.group
+    }
+
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+}
@@ -0,0 +1,219 @@
+use std::{f32::consts::PI, time::Duration};
+
+use crate::{object::Object, cursor::{Position, Velocity}};
+
+/// A TUIO 2 token, the equivalent of a TUIO 1.1 [Object](crate::Object)
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) component_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) rotation_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) rotation_acceleration: f32,
+}
+
+impl Token {
+    /// Creates a new [Token]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `component_id` - the token's component ID, also known as class ID
+    /// * `position` - a normalized [Position]
+    /// * `angle` - an angle in radians
+    pub fn new(session_id: i32, component_id: i32, position: Position, angle: f32) -> Self {
+        Self {
+            session_id,
+            type_id: 0,
+            user_id: 0,
+            component_id,
+            position,
+            angle,
+            velocity: Velocity::default(),
+            rotation_speed: 0f32,
+            acceleration: 0f32,
+            rotation_acceleration: 0f32,
+        }
+    }
+
+    /// Returns this [Token] with a type ID and a user ID
+    /// # Arguments
+    /// * `type_id` - the token's type ID
+    /// * `user_id` - the token's user ID
+    pub fn with_type_user_id(mut self, type_id: u16, user_id: u16) -> Self {
+        self.type_id = type_id;
+        self.user_id = user_id;
+        self
+    }
+
+    /// Returns this [Token] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `rotation_speed` - a rotation speed in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a roation acceleration in radians turn per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        rotation_speed: f32,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_component_id(&self) -> i32 {
+        self.component_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the rotation speed in turn per seconds
+    pub fn get_rotation_speed(&self) -> f32 {
+        self.rotation_speed
+    }
+
+    /// Returns the rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Updates the [Token], computing its velocity, acceleration, rotation speed and rotation acceleration
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32) {
+        let delta_time = delta_time.as_secs_f32();
+
+        let distance = position.distance_from(&self.position);
+        let last_speed = self.velocity.get_speed();
+        let speed = distance / delta_time;
+
+        self.velocity = Velocity {
+            x: (position.x - self.position.x) / delta_time,
+            y: (position.y - self.position.y) / delta_time,
+        };
+
+        self.acceleration = (speed - last_speed) / delta_time;
+        self.position = position;
+
+        let delta_turn = (angle - self.angle) / (2. * PI);
+        let rotation_speed = delta_turn / delta_time;
+
+        self.rotation_acceleration = (rotation_speed - self.rotation_speed) / delta_time;
+        self.rotation_speed = rotation_speed;
+        self.angle = angle;
+    }
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.type_id == other.type_id
+            && self.user_id == other.user_id
+            && self.component_id == other.component_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+    }
+}
+
+impl From<Object> for Token {
+    /// Converts a TUIO 1.1 [Object] into its TUIO 2 equivalent
+    fn from(object: Object) -> Self {
+        Self {
+            session_id: object.session_id,
+            type_id: 0,
+            user_id: 0,
+            component_id: object.class_id,
+            position: object.position,
+            angle: object.angle,
+            velocity: object.velocity,
+            rotation_speed: object.rotation_speed,
+            acceleration: object.acceleration,
+            rotation_acceleration: object.rotation_acceleration,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor::Position, tuio2::Token};
+
+    #[test]
+    fn token_update() {
+        let mut token = Token::new(0, 0, Position { x: 0., y: 0. }, 0.);
+
+        token.update(
+            Duration::from_secs(1),
+            Position { x: 1., y: 1. },
+            90f32.to_radians(),
+        );
+
+        assert_eq!(token.get_x_position(), 1.);
+        assert_eq!(token.get_y_position(), 1.);
+        assert_eq!(token.get_x_velocity(), 1.);
+        assert_eq!(token.get_y_velocity(), 1.);
+        assert_eq!(token.get_acceleration(), SQRT_2);
+        assert_eq!(token.get_angle(), 90f32.to_radians());
+        assert_eq!(token.get_rotation_speed(), 0.25);
+        assert_eq!(token.get_rotation_acceleration(), 0.25);
+    }
+}